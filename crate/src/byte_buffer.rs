@@ -0,0 +1,254 @@
+use crate::{EasyJNIResult, EasyJniError, JavaTypeSignature, MethodSignature};
+use jni::{
+    objects::{GlobalRef, JByteBuffer, JClass, JObject, WeakRef},
+    JNIEnv,
+};
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// Rust owned buffers currently handed to Java, each one is kept alive
+/// until the `ByteBuffer` wrapping it has been garbage collected
+static BUFFERS_IN_JAVA: Mutex<Vec<(WeakRef, Arc<RustBuffer>)>> = Mutex::new(Vec::new());
+
+/// Memory owned by Rust, the owner is boxed so that the address stays the same
+/// even for owners like arrays which store their bytes inline
+struct RustBuffer {
+    _owner: Box<dyn Any + Send + Sync>,
+}
+
+#[derive(Clone)]
+enum BufferSource {
+    Rust(Arc<RustBuffer>),
+    /// A direct buffer created by Java, the global reference keeps its memory alive
+    Java(GlobalRef),
+}
+
+/// Bytes shared with Java through a direct `java.nio.ByteBuffer` without copying.
+///
+/// Buffers coming from Java are held through a global reference which keeps the Java
+/// memory alive.
+///
+/// # Releasing Rust memory
+///
+/// The JVM does not tell native code when a `ByteBuffer` is garbage collected, so memory
+/// created with [`DirectByteBuffer::new`] is only freed once every clone is dropped **and**
+/// a call to [`DirectByteBuffer::collect_released`] finds that Java collected every
+/// `ByteBuffer` handed out for it. Each [`DirectByteBuffer::to_jni_object`] sweeps first,
+/// code that stops handing buffers to Java must call `collect_released` itself,
+/// for example from a periodic native call, or the last buffers are never freed
+///
+/// Only the `ByteBuffer` returned by `to_jni_object` is tracked. On OpenJDK buffers derived
+/// with `slice`, `duplicate` or `asReadOnlyBuffer` keep it reachable, but on Android (ART)
+/// they share the memory without referencing it, so Kotlin code must not keep a derived
+/// buffer after dropping the one Rust returned
+#[derive(Clone)]
+pub struct DirectByteBuffer {
+    source: BufferSource,
+    address: usize,
+    capacity: usize,
+    read_only: bool,
+}
+
+impl<'local> DirectByteBuffer {
+    /// Takes ownership of writable byte storage like `Vec<u8>`, `Box<[u8]>` or a mutable
+    /// memory map, see the type documentation for when the memory is freed
+    pub fn new<T: AsMut<[u8]> + Send + Sync + 'static>(owner: T) -> Self {
+        let mut owner = Box::new(owner);
+        let bytes = (*owner).as_mut();
+        let (address, capacity) = (bytes.as_mut_ptr() as usize, bytes.len());
+
+        DirectByteBuffer::with_owner(owner, address, capacity, false)
+    }
+
+    /// Takes ownership of read-only byte storage like a read-only memory map or
+    /// `&'static [u8]`, Java gets a buffer from `asReadOnlyBuffer` so it cannot write to it
+    pub fn new_read_only<T: AsRef<[u8]> + Send + Sync + 'static>(owner: T) -> Self {
+        let owner = Box::new(owner);
+        let bytes = (*owner).as_ref();
+        let (address, capacity) = (bytes.as_ptr() as usize, bytes.len());
+
+        DirectByteBuffer::with_owner(owner, address, capacity, true)
+    }
+
+    fn with_owner(
+        owner: Box<dyn Any + Send + Sync>,
+        address: usize,
+        capacity: usize,
+        read_only: bool,
+    ) -> Self {
+        let buffer = RustBuffer { _owner: owner };
+
+        DirectByteBuffer {
+            source: BufferSource::Rust(Arc::new(buffer)),
+            address,
+            capacity,
+            read_only,
+        }
+    }
+
+    /// Wraps a direct `java.nio.ByteBuffer` created on the Java side
+    pub fn from_java(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        buffer: &JByteBuffer<'local>,
+    ) -> EasyJNIResult<Self> {
+        let address = env
+            .get_direct_buffer_address(buffer)
+            .map_err(|_| EasyJniError::DirectByteBufferRequired)?;
+        let capacity = env.get_direct_buffer_capacity(buffer)?;
+        let is_read_only = MethodSignature::new(Vec::new(), JavaTypeSignature::Boolean);
+        let read_only = env
+            .call_method(buffer, "isReadOnly", is_read_only.descriptor(), &[])?
+            .z()?;
+
+        Ok(DirectByteBuffer {
+            source: BufferSource::Java(env.new_global_ref(buffer)?),
+            address: address as usize,
+            capacity,
+            read_only,
+        })
+    }
+
+    pub fn signature() -> JavaTypeSignature {
+        JavaTypeSignature::object("java/nio/ByteBuffer")
+    }
+
+    pub fn len(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.capacity == 0
+    }
+
+    /// Whether the memory is owned by Rust rather than by a buffer created in Java
+    pub fn is_rust_owned(&self) -> bool {
+        matches!(self.source, BufferSource::Rust(_))
+    }
+
+    /// Whether Java and Rust may only read the memory
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.address as *mut u8
+    }
+
+    /// # Safety
+    ///
+    /// Java code can write to the buffer at any time, the caller must ensure
+    /// no Java thread writes to it while the slice is alive
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        std::slice::from_raw_parts(self.as_ptr(), self.capacity)
+    }
+
+    /// Fails with `ReadOnlyByteBuffer` for read-only memory
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure no Java thread accesses the buffer and that no other clone
+    /// of this `DirectByteBuffer` creates a slice while the mutable slice is alive
+    pub unsafe fn as_mut_slice(&mut self) -> EasyJNIResult<&mut [u8]> {
+        if self.read_only {
+            return Err(EasyJniError::ReadOnlyByteBuffer);
+        }
+
+        Ok(std::slice::from_raw_parts_mut(self.as_ptr(), self.capacity))
+    }
+
+    /// Creates a `java.nio.ByteBuffer` over the memory. For Rust owned memory a new
+    /// direct buffer is created each time, released buffers are swept with
+    /// [`DirectByteBuffer::collect_released`] first
+    pub fn to_jni_object(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        let rust_buffer = match &self.source {
+            BufferSource::Java(reference) => return Ok(env.new_local_ref(reference.as_obj())?),
+            BufferSource::Rust(rust_buffer) => rust_buffer,
+        };
+
+        DirectByteBuffer::collect_released(env, java_class)?;
+
+        // Safety: the memory is kept alive by the registry below until the buffer is collected
+        let mut buffer =
+            JObject::from(unsafe { env.new_direct_byte_buffer(self.as_ptr(), self.capacity)? });
+
+        // Tracks the writable root buffer, buffers derived from the read-only view
+        // reference the root and not the view
+        if let Some(weak_reference) = env.new_weak_ref(&buffer)? {
+            BUFFERS_IN_JAVA
+                .lock()
+                .map_err(|_| EasyJniError::DirectByteBufferRegistryPoisoned)?
+                .push((weak_reference, rust_buffer.clone()));
+        }
+
+        if self.read_only {
+            let as_read_only = MethodSignature::new(Vec::new(), DirectByteBuffer::signature());
+            let read_only_buffer = env
+                .call_method(&buffer, "asReadOnlyBuffer", as_read_only.descriptor(), &[])?
+                .l()?;
+
+            // The read-only view keeps the writable buffer reachable
+            env.delete_local_ref(std::mem::replace(&mut buffer, read_only_buffer))?;
+        }
+
+        Ok(buffer)
+    }
+
+    /// Frees the Rust owned memory of every `ByteBuffer` Java has garbage collected
+    /// and which is no longer used on the Rust side. Returns how many buffers Java released.
+    /// Nothing else frees that memory, see the type documentation
+    pub fn collect_released(env: &mut JNIEnv<'local>, _: &JClass<'local>) -> EasyJNIResult<usize> {
+        let mut buffers = BUFFERS_IN_JAVA
+            .lock()
+            .map_err(|_| EasyJniError::DirectByteBufferRegistryPoisoned)?;
+
+        let before = buffers.len();
+        let mut outcome = Ok(());
+
+        buffers.retain(
+            |(weak_reference, _)| match weak_reference.is_garbage_collected(env) {
+                Ok(collected) => !collected,
+                Err(error) => {
+                    outcome = Err(error);
+                    true
+                }
+            },
+        );
+
+        outcome?;
+
+        Ok(before - buffers.len())
+    }
+}
+
+impl fmt::Debug for DirectByteBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectByteBuffer")
+            .field("rust_owned", &self.is_rust_owned())
+            .field("address", &self.as_ptr())
+            .field("capacity", &self.capacity)
+            .field("read_only", &self.read_only)
+            .finish()
+    }
+}
+
+/// Two buffers are equal when they share the same memory
+impl PartialEq for DirectByteBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        (self.address, self.capacity) == (other.address, other.capacity)
+    }
+}
+
+impl PartialOrd for DirectByteBuffer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.address, self.capacity).partial_cmp(&(other.address, other.capacity))
+    }
+}
@@ -1,135 +1,691 @@
-use crate::{EasyJNIResult, EasyJniError, JavaType, JavaTypeSignature};
+#[cfg(feature = "conversion")]
+use crate::{overload::STATIC_MODIFIER, JavaObject};
+use crate::{
+    with_local_frame, with_local_frame_returning_local, EasyJNIResult, EasyJniError, JavaType,
+    JavaTypeSignature, MethodSignature,
+};
+#[cfg(feature = "conversion")]
+use jni::objects::{JObjectArray, JString};
 use jni::{
-    objects::{JClass, JObject, JObjectArray, JValueOwned},
-    strings::JNIString,
-    sys::{jarray, jint, jsize},
+    objects::{JClass, JObject, JValue},
     JNIEnv,
 };
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
-pub struct JavaArray {
-    size: usize,
-    java_type: JavaTypeSignature,
-    values: Vec<JavaType>,
+/// A Java class together with the constructor arguments and the fields of new instances,
+/// for example `Class::new("ResultOfArrayString").field("successData", values)`
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Class<'local> {
+    name: &'local str,
+    /// The declared types are `None` for `null` values added without a type
+    arguments: Vec<(Option<JavaTypeSignature>, JavaType)>,
+    fields: Vec<(String, Option<JavaTypeSignature>, JavaType)>,
+    skip_constructor: bool,
 }
 
-impl<'local> JavaArray {
-    pub fn new(java_type: JavaTypeSignature) -> Self {
-        JavaArray {
-            size: 0,
-            java_type,
-            values: Vec::default(),
+impl<'local> Class<'local> {
+    pub fn new(name: &'local str) -> Class<'local> {
+        Class {
+            name,
+            arguments: Vec::new(),
+            fields: Vec::new(),
+            skip_constructor: false,
         }
     }
 
-    pub fn resize(mut self, size: usize) -> Self {
-        self.size = size;
-
-        self
+    pub fn name(&self) -> &'local str {
+        self.name
     }
 
-    pub fn add_value(mut self, value: JavaType) -> EasyJNIResult<Self> {
-        if value.to_java_type_signature() != self.java_type {
-            return Err(EasyJniError::JavaTypeMismatch);
-        }
+    /// Adds an argument for the constructor, the constructor is selected by the types
+    /// of the arguments so `JavaType::Int` selects a constructor taking an `int`.
+    /// Use [`Class::argument_as`] for boxed parameters like `Integer` or to pass `null`,
+    /// `JavaType::Null` has no type and fails with `UntypedNullArgument` on creation
+    pub fn argument(mut self, value: impl Into<JavaType>) -> Self {
+        let value = value.into();
+        let signature = (!value.is_null()).then(|| value.to_java_type_signature());
 
-        self.values.push(value);
+        self.arguments.push((signature, value));
 
-        Ok(self)
+        self
     }
 
-    pub fn values(&self) -> &Vec<JavaType> {
-        self.values.as_ref()
-    }
+    /// Adds an argument for a constructor parameter declared with the type `signature`,
+    /// primitive values are boxed for parameters of reference types
+    pub fn argument_as(mut self, signature: JavaTypeSignature, value: impl Into<JavaType>) -> Self {
+        self.arguments.push((Some(signature), value.into()));
 
-    pub fn create(
-        env: &mut JNIEnv<'local>,
-        _: &JClass<'local>,
-        java_type: JavaTypeSignature,
-        size: usize,
-    ) -> EasyJNIResult<JObjectArray<'local>> {
-        let java_signature = java_type.java_class_name();
+        self
+    }
 
-        let jarray = env.new_object_array(size as jsize, java_signature, JObject::null())?;
+    /// The signature of the constructor selected by the arguments, fails with
+    /// `UntypedNullArgument` for a `JavaType::Null` added through [`Class::argument`]
+    pub fn constructor(&self) -> EasyJNIResult<MethodSignature> {
+        let parameters = self
+            .arguments
+            .iter()
+            .enumerate()
+            .map(|(index, (signature, _))| {
+                signature
+                    .clone()
+                    .ok_or(EasyJniError::UntypedNullArgument { index })
+            })
+            .collect::<EasyJNIResult<_>>()?;
 
-        Ok(jarray)
+        Ok(MethodSignature::constructor(parameters))
     }
 
-    pub fn build(
-        &'local self,
-        env: &mut JNIEnv<'local>,
-        java_class: &'local JClass<'local>,
-    ) -> EasyJNIResult<jarray> {
-        let class = self.java_type.java_class_name();
+    /// Builds instances with `AllocObject` instead of calling a constructor, so field
+    /// initializers and `init` blocks do not run and every field starts out zeroed or `null`
+    pub fn skip_constructor(mut self) -> Self {
+        self.skip_constructor = true;
 
-        let jarray = env.new_object_array(self.size as jsize, class, JObject::null())?;
+        self
+    }
 
-        for (i, s) in self.values.iter().enumerate() {
-            let object = s.to_jni_object(env, &java_class)?;
+    /// Sets the field `name` to `value` on every instance built, the field descriptor
+    /// is derived from the value so `JavaType::Int` sets an `int` field.
+    /// Use [`Class::field_as`] for boxed fields like `Integer` or to set `null`,
+    /// `JavaType::Null` has no type and fails with `UntypedNullField` on build
+    pub fn field(mut self, name: &str, value: impl Into<JavaType>) -> Self {
+        let value = value.into();
+        let signature = (!value.is_null()).then(|| value.to_java_type_signature());
 
-            env.set_object_array_element(&jarray, i as jint, object)?;
-        }
+        self.fields.push((name.to_owned(), signature, value));
 
-        Ok(jarray.into_raw())
+        self
     }
-}
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-pub struct Class<'local> {
-    name: &'local str,
-}
+    /// Sets the field `name` declared with the type `signature`,
+    /// primitive values are boxed for fields of reference types
+    pub fn field_as(
+        mut self,
+        name: &str,
+        signature: JavaTypeSignature,
+        value: impl Into<JavaType>,
+    ) -> Self {
+        self.fields
+            .push((name.to_owned(), Some(signature), value.into()));
 
-impl<'local> Class<'local> {
-    pub fn new(name: &'local str) -> Class {
-        Class { name }
+        self
     }
 
-    pub fn name(&self) -> &'local str {
-        self.name
+    /// Creates an instance by calling the constructor selected by the arguments
+    pub fn create(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        self.create_with(env, java_class, &self.constructor()?)
     }
 
-    pub fn create(
+    /// Creates an instance by calling the constructor Java would choose for the values
+    /// of the arguments, like one generated for `@JvmOverloads` default parameters.
+    /// The signatures given to [`Class::argument_as`] are ignored
+    pub fn create_overloaded(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect::<Vec<_>>();
+
+        let constructor = with_local_frame(env, java_class, 2, |env, java_class| {
+            let class = env.find_class(self.name)?;
+
+            MethodSignature::resolve_constructor(env, java_class, &class, &arguments)
+        })?;
+
+        self.create_with(env, java_class, &constructor)
+    }
+
+    /// Creates an instance without running any constructor
+    pub fn allocate(
         &self,
         env: &mut JNIEnv<'local>,
         _java_class: &JClass<'local>,
     ) -> EasyJNIResult<JObject<'local>> {
-        let class = env.find_class(&self.name)?;
+        let class = env.find_class(self.name)?;
 
-        let object = env.alloc_object(class)?;
+        let object = env.alloc_object(&class)?;
+        env.delete_local_ref(class)?;
 
         Ok(object)
     }
 
-    pub fn create_and_build(
-        &'local self,
+    /// Creates an instance and sets every field added with [`Class::field`] in order,
+    /// an error names the field that could not be set
+    pub fn build(
+        &self,
         env: &mut JNIEnv<'local>,
-        java_class: &'local JClass<'local>,
-        name: &str,
-        value: &'local JavaType,
+        java_class: &JClass<'local>,
     ) -> EasyJNIResult<JObject<'local>> {
-        let class = env.find_class(&self.name)?;
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, signature, value)| match signature {
+                Some(signature) => Ok((name, signature, value)),
+                None => Err(EasyJniError::Field {
+                    name: name.clone(),
+                    error: Box::new(EasyJniError::UntypedNullField),
+                }),
+            })
+            .collect::<EasyJNIResult<Vec<_>>>()?;
 
-        let object = env.alloc_object(class)?;
+        let object = if self.skip_constructor {
+            self.allocate(env, java_class)?
+        } else {
+            self.create(env, java_class)?
+        };
 
-        let field_name = JNIString::from(name);
-        let field_value = value.to_jni_object(env, &java_class)?;
-        let field_value = JValueOwned::Object(field_value);
+        for (name, signature, value) in fields {
+            if let Err(error) = Self::set_field(env, java_class, &object, name, signature, value) {
+                env.delete_local_ref(object)?;
 
-        env.set_field(
-            &object,
-            field_name,
-            value.java_class_name(),
-            field_value.borrow(),
-        )?;
+                return Err(EasyJniError::Field {
+                    name: name.clone(),
+                    error: Box::new(error),
+                });
+            }
+        }
 
         Ok(object)
     }
 
+    /// Creates an instance with a single field set, the value is always boxed
+    pub fn create_and_build(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        value: &JavaType,
+    ) -> EasyJNIResult<JObject<'local>> {
+        let signature = value.to_java_type_signature().boxed();
+
+        self.clone()
+            .field_as(name, signature, value.clone())
+            .build(env, java_class)
+    }
+
     pub fn find(
         self,
         env: &mut JNIEnv<'local>,
         _: JClass<'local>,
     ) -> EasyJNIResult<JClass<'local>> {
-        Ok(env.find_class(&self.name)?)
+        Ok(env.find_class(self.name)?)
+    }
+
+    /// Whether instances of the class `class_name` can be assigned to this class,
+    /// like `isAssignableFrom` of `java.lang.Class`
+    pub fn is_assignable_from(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class_name: &str,
+    ) -> EasyJNIResult<bool> {
+        let class_name = class_name.replace('.', "/");
+
+        with_local_frame(env, java_class, 2, |env, _| {
+            let class = env.find_class(self.name)?;
+            let other = env.find_class(class_name)?;
+
+            // `IsAssignableFrom` takes the classes the other way around
+            Ok(env.is_assignable_from(&other, &class)?)
+        })
+    }
+
+    /// Reads the static field `name`, like a Kotlin `const val` or a `@JvmField`
+    /// companion member, its type is looked up through reflection
+    #[cfg(feature = "conversion")]
+    pub fn static_field(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaType> {
+        let signature = with_local_frame(env, java_class, 2, |env, java_class| {
+            let class = env.find_class(self.name)?;
+
+            JavaObject::declared_field_type(env, java_class, &class, name)
+        })
+        .map_err(|error| EasyJniError::Field {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })?;
+
+        self.static_field_as(env, java_class, name, signature)
+    }
+
+    /// Reads the static field `name` declared with the type `signature`
+    #[cfg(feature = "conversion")]
+    pub fn static_field_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        signature: JavaTypeSignature,
+    ) -> EasyJNIResult<JavaType> {
+        with_local_frame(env, java_class, 4, |env, java_class| {
+            let class = env.find_class(self.name)?;
+            let value = env.get_static_field(class, name, signature.java_signature())?;
+
+            crate::to_rust(env, java_class, value.borrow())
+        })
+        .map_err(|error| EasyJniError::Field {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// Calls the static method `name`, like a Kotlin top level function or a
+    /// `@JvmStatic` companion member. The method descriptor is built from the exact types
+    /// of the arguments and `return_type`, see [`JavaObject::call_method`] for the
+    /// calls that need [`Class::call_static_method_as`] instead
+    #[cfg(feature = "conversion")]
+    pub fn call_static_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+        return_type: JavaTypeSignature,
+    ) -> EasyJNIResult<JavaType> {
+        let signature =
+            MethodSignature::from_typed_arguments(arguments, return_type).map_err(|error| {
+                EasyJniError::Method {
+                    name: name.to_owned(),
+                    error: Box::new(error),
+                }
+            })?;
+
+        self.call_static_method_as(env, java_class, name, &signature, arguments)
+    }
+
+    /// Calls the static method `name` choosing the overload the way Java would,
+    /// see [`MethodSignature::resolve_method`]
+    #[cfg(feature = "conversion")]
+    pub fn call_overloaded_static_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let signature = with_local_frame(env, java_class, 2, |env, java_class| {
+            let class = env.find_class(self.name)?;
+
+            MethodSignature::resolve_method(env, java_class, &class, name, true, arguments)
+        })?;
+
+        self.call_static_method_as(env, java_class, name, &signature, arguments)
+    }
+
+    /// Calls the static method `name` declared with `signature`
+    #[cfg(feature = "conversion")]
+    pub fn call_static_method_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        signature: &MethodSignature,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let capacity = arguments.len() as i32 + 2;
+
+        with_local_frame(env, java_class, capacity, |env, java_class| {
+            let arguments = signature.to_jni_arguments(env, java_class, arguments)?;
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.borrow())
+                .collect::<Vec<JValue>>();
+
+            let class = env.find_class(self.name)?;
+            let outcome =
+                env.call_static_method(class, name, signature.descriptor(), &arguments)?;
+
+            crate::to_rust(env, java_class, outcome.borrow())
+        })
+        .map_err(|error| EasyJniError::Method {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// The singleton of a Kotlin `object`, held by its static `INSTANCE` field
+    #[cfg(feature = "conversion")]
+    pub fn object_instance(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JavaObject> {
+        let signature = JavaTypeSignature::object(self.name);
+
+        JavaObject::try_from(self.static_field_as(env, java_class, "INSTANCE", signature)?)
+    }
+
+    /// The `companion object` of a Kotlin class. Kotlin stores it in a static field named
+    /// after the companion, `Companion` by default or `Factory` for `companion object Factory`
+    #[cfg(feature = "conversion")]
+    pub fn companion(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JavaObject> {
+        let companion =
+            self.companion_name(env, java_class)
+                .map_err(|error| EasyJniError::Field {
+                    name: "Companion".to_owned(),
+                    error: Box::new(error),
+                })?;
+        let signature = JavaTypeSignature::object(&format!("{}${}", self.name, companion));
+
+        JavaObject::try_from(self.static_field_as(env, java_class, &companion, signature)?)
+    }
+
+    /// Calls the method `name` of a Kotlin `object`, members marked `@JvmStatic`
+    /// are called as static methods. The overload is chosen the way Java would
+    #[cfg(feature = "conversion")]
+    pub fn call_object_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let instance = self.object_instance(env, java_class)?;
+
+        self.call_singleton_method(env, java_class, &instance, name, arguments)
+    }
+
+    /// Reads the property `name` of a Kotlin `object` through its getter,
+    /// `const val` and `@JvmField` properties are read from their static field
+    #[cfg(feature = "conversion")]
+    pub fn object_property(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaType> {
+        let instance = self.object_instance(env, java_class)?;
+
+        self.singleton_property(env, java_class, &instance, name)
+    }
+
+    /// Calls the method `name` of the `companion object` of a Kotlin class,
+    /// the overload is chosen the way Java would
+    #[cfg(feature = "conversion")]
+    pub fn call_companion_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let companion = self.companion(env, java_class)?;
+
+        self.call_singleton_method(env, java_class, &companion, name, arguments)
+    }
+
+    /// Reads the property `name` of the `companion object` of a Kotlin class through
+    /// its getter, `const val` and `@JvmField` properties are read from their static field
+    #[cfg(feature = "conversion")]
+    pub fn companion_property(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaType> {
+        let companion = self.companion(env, java_class)?;
+
+        self.singleton_property(env, java_class, &companion, name)
+    }
+
+    /// Sets the static field `name`, the field descriptor is derived from the value.
+    /// `JavaType::Null` has no type and fails with `UntypedNullField`,
+    /// use [`Class::set_static_field_as`] to set `null`
+    pub fn set_static_field(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        value: impl Into<JavaType>,
+    ) -> EasyJNIResult<()> {
+        let value = value.into();
+
+        if value.is_null() {
+            return Err(EasyJniError::Field {
+                name: name.to_owned(),
+                error: Box::new(EasyJniError::UntypedNullField),
+            });
+        }
+
+        let signature = value.to_java_type_signature();
+
+        self.set_static_field_as(env, java_class, name, signature, value)
+    }
+
+    /// Sets the static field `name` declared with the type `signature`,
+    /// primitive values are boxed for fields of reference types
+    pub fn set_static_field_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        signature: JavaTypeSignature,
+        value: impl Into<JavaType>,
+    ) -> EasyJNIResult<()> {
+        let value = value.into();
+
+        with_local_frame(env, java_class, 4, |env, java_class| {
+            let class = env.find_class(self.name)?;
+            let field = env.get_static_field_id(&class, name, signature.java_signature())?;
+            let field_value = value.to_jni_jvalue_as(env, java_class, &signature)?;
+
+            env.set_static_field(&class, field, field_value.borrow())?;
+
+            Ok(())
+        })
+        .map_err(|error| EasyJniError::Field {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// The name of the companion, which is the name of a static field whose type
+    /// is the nested class of the same name like `Outer$Factory`
+    #[cfg(feature = "conversion")]
+    fn companion_name(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<String> {
+        with_local_frame(env, java_class, 4, |env, java_class| {
+            let class = env.find_class(self.name)?;
+
+            let default = JavaTypeSignature::object(&format!("{}$Companion", self.name));
+
+            match env.get_static_field_id(&class, "Companion", default.java_signature()) {
+                Ok(_) => return Ok("Companion".to_owned()),
+                // `NoSuchFieldError`, the companion has another name
+                Err(
+                    jni::errors::Error::JavaException | jni::errors::Error::FieldNotFound { .. },
+                ) => env.exception_clear()?,
+                Err(error) => return Err(error.into()),
+            }
+
+            let get_declared_fields = MethodSignature::new(
+                Vec::new(),
+                JavaTypeSignature::array(JavaTypeSignature::object("java/lang/reflect/Field")),
+            );
+
+            let fields = env
+                .call_method(
+                    &class,
+                    "getDeclaredFields",
+                    get_declared_fields.descriptor(),
+                    &[],
+                )?
+                .l()?;
+            let fields = JObjectArray::from(fields);
+
+            for index in 0..env.get_array_length(&fields)? {
+                let companion = with_local_frame(env, java_class, 4, |env, java_class| {
+                    let field = env.get_object_array_element(&fields, index)?;
+
+                    self.companion_field_name(env, java_class, &field)
+                })?;
+
+                if let Some(companion) = companion {
+                    return Ok(companion);
+                }
+            }
+
+            Err(EasyJniError::FieldNotFound)
+        })
+    }
+
+    /// The name of `field` when it is static and its type is the nested class
+    /// of the same name
+    #[cfg(feature = "conversion")]
+    fn companion_field_name<'frame>(
+        &self,
+        env: &mut JNIEnv<'frame>,
+        java_class: &JClass<'frame>,
+        field: &JObject<'frame>,
+    ) -> EasyJNIResult<Option<String>> {
+        let get_name = MethodSignature::new(Vec::new(), JavaTypeSignature::String);
+        let get_modifiers = MethodSignature::new(Vec::new(), JavaTypeSignature::Int);
+        let get_type =
+            MethodSignature::new(Vec::new(), JavaTypeSignature::object("java/lang/Class"));
+
+        let modifiers = env
+            .call_method(field, "getModifiers", get_modifiers.descriptor(), &[])?
+            .i()?;
+
+        if modifiers & STATIC_MODIFIER == 0 {
+            return Ok(None);
+        }
+
+        let name = env
+            .call_method(field, "getName", get_name.descriptor(), &[])?
+            .l()?;
+        let name = JString::from(name);
+        // Safety: `Field.getName` returns a `java.lang.String`
+        let name: String = unsafe { env.get_string_unchecked(&name)? }.into();
+
+        let field_type = env
+            .call_method(field, "getType", get_type.descriptor(), &[])?
+            .l()?;
+        let type_name = JavaObject::class_name_of(env, java_class, &JClass::from(field_type))?;
+
+        Ok((type_name == format!("{}${}", self.name, name)).then_some(name))
+    }
+
+    #[cfg(feature = "conversion")]
+    fn call_singleton_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        singleton: &JavaObject,
+        name: &str,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        match singleton.call_overloaded_method(env, java_class, name, arguments) {
+            // `@JvmStatic` members of an `object` only exist as static methods
+            Err(EasyJniError::NoApplicableMethod { .. }) => {
+                self.call_overloaded_static_method(env, java_class, name, arguments)
+            }
+            outcome => outcome,
+        }
+    }
+
+    #[cfg(feature = "conversion")]
+    fn singleton_property(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        singleton: &JavaObject,
+        name: &str,
+    ) -> EasyJNIResult<JavaType> {
+        let getter = Self::getter_name(name);
+
+        match self.call_singleton_method(env, java_class, singleton, &getter, &[]) {
+            // Properties without a getter are static fields of the class
+            Err(EasyJniError::NoApplicableMethod { .. }) => {
+                self.static_field(env, java_class, name)
+            }
+            outcome => outcome,
+        }
+    }
+
+    /// The name Kotlin gives to the getter of a property,
+    /// `count` is read by `getCount` while `isOpen` keeps its name
+    #[cfg(feature = "conversion")]
+    fn getter_name(property: &str) -> String {
+        let is_prefixed = property
+            .strip_prefix("is")
+            .is_some_and(|rest| rest.starts_with(|first: char| first.is_uppercase()));
+
+        if is_prefixed {
+            return property.to_owned();
+        }
+
+        let mut characters = property.chars();
+
+        match characters.next() {
+            Some(first) => format!("get{}{}", first.to_uppercase(), characters.as_str()),
+            None => "get".to_owned(),
+        }
+    }
+
+    fn create_with(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        constructor: &MethodSignature,
+    ) -> EasyJNIResult<JObject<'local>> {
+        let capacity = self.arguments.len() as i32 + 1;
+
+        with_local_frame_returning_local(env, java_class, capacity, |env, java_class| {
+            let arguments = constructor
+                .parameters()
+                .iter()
+                .zip(&self.arguments)
+                .map(|(parameter, (_, value))| value.to_jni_jvalue_as(env, java_class, parameter))
+                .collect::<EasyJNIResult<Vec<_>>>()?;
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.borrow())
+                .collect::<Vec<JValue>>();
+
+            let class = env.find_class(self.name)?;
+
+            Ok(env.new_object(class, constructor.descriptor(), &arguments)?)
+        })
+    }
+
+    fn set_field(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        object: &JObject<'local>,
+        name: &str,
+        signature: &JavaTypeSignature,
+        value: &JavaType,
+    ) -> EasyJNIResult<()> {
+        // Every field gets its own frame so large values are released right away
+        with_local_frame(env, java_class, 4, |env, java_class| {
+            let field_value = value.to_jni_jvalue_as(env, java_class, signature)?;
+
+            env.set_field(
+                object,
+                name,
+                signature.java_signature(),
+                field_value.borrow(),
+            )?;
+
+            Ok(())
+        })
     }
 }
@@ -1,24 +1,21 @@
-use crate::{EasyJNIResult, EasyJniError, JavaType};
+use crate::{
+    DirectByteBuffer, EasyJNIResult, EasyJniError, JavaArray, JavaObject, JavaType,
+    JavaTypeSignature, MethodSignature,
+};
 use jni::{
     self,
-    objects::{JClass, JObject, JString, JValue, JValueGen},
+    objects::{JByteBuffer, JClass, JObject, JString, JValue, JValueGen},
     JNIEnv,
 };
 
 pub fn to_rust<'local>(
     env: &mut JNIEnv<'local>,
     // Static class which owns this method.
-    _class: &JClass<'local>,
+    class: &JClass<'local>,
     input: JValue,
 ) -> EasyJNIResult<JavaType> {
     let outcome = match input {
-        JValueGen::Byte(value) => {
-            if value < 0 {
-                return Err(crate::EasyJniError::ByteLessThanZeroNotSupported);
-            } else {
-                JavaType::Byte(value as u8)
-            }
-        }
+        JValueGen::Byte(value) => JavaType::Byte(value),
         JValueGen::Double(value) => JavaType::Double(value),
         JValueGen::Float(value) => JavaType::Float(value),
         JValueGen::Int(value) => JavaType::Int(value),
@@ -33,20 +30,111 @@ pub fn to_rust<'local>(
                 JavaType::Boolean(false)
             }
         }
+        JValueGen::Char(value) => JavaType::Char(JavaType::char_from_utf16(value)?),
         JValueGen::Void => JavaType::Void,
         JValueGen::Object(value) => {
             let value = unsafe { JObject::from_raw(value.as_raw()) };
 
-            if let Ok(inner_value) = JString::try_from(value) {
-                let string_outcome = env.get_string(&inner_value)?.into();
+            if value.is_null() {
+                JavaType::Null
+            } else if env.is_instance_of(&value, JavaTypeSignature::String.java_class_name())? {
+                let inner_value = JString::from(value);
+                // Safety: checked to be a `java.lang.String` above, `get_string` would
+                // look up both classes again and leak the two local references
+                let string_outcome = unsafe { env.get_string_unchecked(&inner_value)? }.into();
 
-                return Ok(JavaType::String(string_outcome));
+                JavaType::String(string_outcome)
+            } else if let Some(java_type) = boxed_type(env, &value)? {
+                return unbox_as(env, class, &value, java_type);
+            } else if is_direct_byte_buffer(env, &value)? {
+                let buffer = JByteBuffer::from(value);
+
+                JavaType::ByteBuffer(DirectByteBuffer::from_java(env, class, &buffer)?)
             } else {
-                return Err(EasyJniError::UnsupportedJavaType);
+                let object_class = env.get_object_class(&value)?;
+                let class_name = JavaObject::class_name_of(env, class, &object_class)?;
+                env.delete_local_ref(object_class)?;
+
+                match JavaTypeSignature::from_class_name(&class_name)? {
+                    JavaTypeSignature::Array(element) => {
+                        JavaType::Array(JavaArray::from_java(env, class, &value, *element)?)
+                    }
+                    _ => JavaType::Object(JavaObject::with_class_name(
+                        env,
+                        class,
+                        &value,
+                        &class_name,
+                    )?),
+                }
             }
         }
-        _ => return Err(EasyJniError::UnsupportedJavaType),
     };
 
     Ok(outcome)
 }
+
+/// Reads the primitive value out of a boxed object like `java.lang.Integer`
+pub fn unbox<'local>(
+    env: &mut JNIEnv<'local>,
+    class: &JClass<'local>,
+    object: &JObject<'local>,
+) -> EasyJNIResult<JavaType> {
+    let java_type = boxed_type(env, object)?.ok_or(EasyJniError::UnsupportedJavaType)?;
+
+    unbox_as(env, class, object, java_type)
+}
+
+fn unbox_as<'local>(
+    env: &mut JNIEnv<'local>,
+    class: &JClass<'local>,
+    object: &JObject<'local>,
+    java_type: JavaTypeSignature,
+) -> EasyJNIResult<JavaType> {
+    let method = java_type
+        .unboxing_method()
+        .ok_or(EasyJniError::UnsupportedJavaType)?;
+    let descriptor = MethodSignature::new(Vec::new(), java_type.clone());
+    let value = env.call_method(object, method, descriptor.descriptor(), &[])?;
+
+    to_rust(env, class, value.borrow())
+}
+
+fn is_direct_byte_buffer<'local>(
+    env: &mut JNIEnv<'local>,
+    object: &JObject<'local>,
+) -> EasyJNIResult<bool> {
+    if !env.is_instance_of(object, DirectByteBuffer::signature().java_class_name())? {
+        return Ok(false);
+    }
+
+    let descriptor = MethodSignature::new(Vec::new(), JavaTypeSignature::Boolean);
+
+    Ok(env
+        .call_method(object, "isDirect", descriptor.descriptor(), &[])?
+        .z()?)
+}
+
+/// The primitive type boxed by `object`, `None` if it is not a boxed primitive
+fn boxed_type<'local>(
+    env: &mut JNIEnv<'local>,
+    object: &JObject<'local>,
+) -> EasyJNIResult<Option<JavaTypeSignature>> {
+    let primitives = [
+        JavaTypeSignature::Byte,
+        JavaTypeSignature::Short,
+        JavaTypeSignature::Int,
+        JavaTypeSignature::Long,
+        JavaTypeSignature::Float,
+        JavaTypeSignature::Double,
+        JavaTypeSignature::Boolean,
+        JavaTypeSignature::Char,
+    ];
+
+    for java_type in primitives {
+        if env.is_instance_of(object, java_type.java_class_name())? {
+            return Ok(Some(java_type));
+        }
+    }
+
+    Ok(None)
+}
@@ -6,13 +6,86 @@ pub type EasyJNIResult<T> = Result<T, EasyJniError>;
 pub enum EasyJniError {
     ArrayOfVoidNotAllowed,
     UnsupportedJavaType,
-    /// Any Java byte (i8) value less than 0 will give a hard error since Rust byte is unsigned.
-    ByteLessThanZeroNotSupported,
     /// The `JavaType` does not match the required type
     JavaTypeMismatch,
     FromJniCrate(String),
     /// Tried to create a class without adding the class name first
     ClassNameRequiredToCreateClass,
+    /// A Java `char` is a single UTF-16 code unit so Rust `char`s outside
+    /// the Basic Multilingual Plane cannot be converted
+    CharOutsideBmp(char),
+    /// A Java `char` holding half of a surrogate pair cannot be converted to a Rust `char`
+    LoneSurrogate(u16),
+    /// Only the eight primitive types of java are allowed, for example as the elements of a primitive array
+    PrimitiveTypeRequired,
+    /// The array holds more values than its size, `index` is the first value that does not fit
+    ArrayIndexOutOfBounds {
+        index: usize,
+        size: usize,
+    },
+    /// The array holds fewer values than its size and has no fill value
+    MissingArrayValue {
+        index: usize,
+        size: usize,
+    },
+    /// The value at `index` does not match the element type of the array
+    ArrayElementMismatch {
+        index: usize,
+    },
+    /// The `java.nio.ByteBuffer` is not a direct buffer so its memory cannot be accessed
+    DirectByteBufferRequired,
+    /// The `java.nio.ByteBuffer` is read-only so its memory cannot be written
+    ReadOnlyByteBuffer,
+    /// A thread panicked while holding the list of Rust buffers handed to Java
+    DirectByteBufferRegistryPoisoned,
+    /// No field with that name is declared by the class or any of its superclasses
+    FieldNotFound,
+    /// Reading or setting the field `name` failed with `error`
+    Field {
+        name: String,
+        error: Box<EasyJniError>,
+    },
+    /// Calling the method `name` failed with `error`
+    Method {
+        name: String,
+        error: Box<EasyJniError>,
+    },
+    /// The argument at `index` is `JavaType::Null` while the method descriptor is built
+    /// from the types of the arguments, `null` has no type to build it from
+    UntypedNullArgument {
+        index: usize,
+    },
+    /// A field is set to `JavaType::Null` while its descriptor is derived from the value,
+    /// `null` has no type to derive it from
+    UntypedNullField,
+    /// The number of arguments does not match the number of parameters of the method
+    ArgumentCountMismatch {
+        expected: usize,
+        found: usize,
+    },
+    /// An object of the class `class_name` is not an instance of `target`
+    InvalidCast {
+        class_name: String,
+        target: String,
+    },
+    /// None of the overloads of the method `name` accepts the arguments,
+    /// constructors are named `<init>`
+    NoApplicableMethod {
+        name: String,
+    },
+    /// More than one overload of the method `name` accepts the arguments and none
+    /// of them is more specific than the others, `candidates` are their descriptors
+    AmbiguousMethodCall {
+        name: String,
+        candidates: Vec<String>,
+    },
+    /// A JNI type or method descriptor could not be parsed,
+    /// `position` is the byte offset where parsing failed
+    InvalidDescriptor {
+        descriptor: String,
+        position: usize,
+        reason: &'static str,
+    },
 }
 
 impl From<JniError> for EasyJniError {
@@ -0,0 +1,826 @@
+use crate::{
+    with_local_frame, DirectByteBuffer, EasyJNIResult, EasyJniError, JavaObject, JavaPrimitive,
+    JavaType, JavaTypeSignature,
+};
+use jni::{
+    objects::{JClass, JObject, JObjectArray, JPrimitiveArray, JString},
+    sys::{jarray, jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort, jsize},
+    JNIEnv,
+};
+use std::ops::Index;
+
+/// How the elements of a `JavaArray` are stored on the Java side
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum ArrayRepresentation {
+    /// An object array where primitives are boxed, like `Integer[]` or Kotlin `Array<Int>`
+    #[default]
+    Boxed,
+    /// A primitive array like `int[]` or Kotlin `IntArray`
+    Primitive,
+}
+
+/// A Rust type with a known Java type which can be stored in a typed `JavaArray<T>`
+pub trait JavaArrayElement:
+    Clone + Into<JavaType> + TryFrom<JavaType, Error = EasyJniError>
+{
+    /// The element type of a Java array holding `Self`
+    fn java_type() -> JavaTypeSignature;
+
+    /// Creates a Java primitive array like `int[]` holding a copy of `values`,
+    /// fails with `PrimitiveTypeRequired` for element types that are not primitives
+    fn primitive_array<'local>(
+        _env: &mut JNIEnv<'local>,
+        _java_class: &JClass<'local>,
+        _values: &[Self],
+    ) -> EasyJNIResult<JObject<'local>> {
+        Err(EasyJniError::PrimitiveTypeRequired)
+    }
+}
+
+macro_rules! java_array_elements {
+    ($($rust_type:ty => $java_type:expr),* $(,)?) => {
+        $(
+            impl JavaArrayElement for $rust_type {
+                fn java_type() -> JavaTypeSignature {
+                    $java_type
+                }
+            }
+        )*
+    };
+}
+
+/// Element types stored in Java primitive arrays exactly like in Rust,
+/// so their values are copied in a single `Set<Type>ArrayRegion` call
+macro_rules! primitive_array_elements {
+    ($($rust_type:ty => $java_type:expr),* $(,)?) => {
+        $(
+            impl JavaArrayElement for $rust_type {
+                fn java_type() -> JavaTypeSignature {
+                    $java_type
+                }
+
+                fn primitive_array<'local>(
+                    env: &mut JNIEnv<'local>,
+                    java_class: &JClass<'local>,
+                    values: &[Self],
+                ) -> EasyJNIResult<JObject<'local>> {
+                    Ok(JObject::from(JavaArray::<JavaType>::from_slice(env, java_class, values)?))
+                }
+            }
+        )*
+    };
+}
+
+primitive_array_elements!(
+    i8 => JavaTypeSignature::Byte,
+    i16 => JavaTypeSignature::Short,
+    i32 => JavaTypeSignature::Int,
+    i64 => JavaTypeSignature::Long,
+    f32 => JavaTypeSignature::Float,
+    f64 => JavaTypeSignature::Double,
+);
+
+java_array_elements!(
+    String => JavaTypeSignature::String,
+    JavaObject => JavaTypeSignature::object("java/lang/Object"),
+    DirectByteBuffer => DirectByteBuffer::signature(),
+);
+
+impl JavaArrayElement for bool {
+    fn java_type() -> JavaTypeSignature {
+        JavaTypeSignature::Boolean
+    }
+
+    fn primitive_array<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        values: &[Self],
+    ) -> EasyJNIResult<JObject<'local>> {
+        let values = values
+            .iter()
+            .map(|value| *value as jboolean)
+            .collect::<Vec<_>>();
+
+        Ok(JObject::from(JavaArray::<JavaType>::from_slice(
+            env, java_class, &values,
+        )?))
+    }
+}
+
+impl JavaArrayElement for char {
+    fn java_type() -> JavaTypeSignature {
+        JavaTypeSignature::Char
+    }
+
+    fn primitive_array<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        values: &[Self],
+    ) -> EasyJNIResult<JObject<'local>> {
+        let values = values
+            .iter()
+            .map(|value| JavaType::char_to_utf16(*value))
+            .collect::<EasyJNIResult<Vec<jchar>>>()?;
+
+        Ok(JObject::from(JavaArray::<JavaType>::from_slice(
+            env, java_class, &values,
+        )?))
+    }
+}
+
+/// `None` is stored as `null`, so it can only be built into a primitive array
+/// when every value is set
+impl<T: JavaArrayElement> JavaArrayElement for Option<T>
+where
+    Option<T>: TryFrom<JavaType, Error = EasyJniError>,
+{
+    fn java_type() -> JavaTypeSignature {
+        T::java_type()
+    }
+
+    fn primitive_array<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        values: &[Self],
+    ) -> EasyJNIResult<JObject<'local>> {
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                value
+                    .clone()
+                    .ok_or(EasyJniError::ArrayElementMismatch { index })
+            })
+            .collect::<EasyJNIResult<Vec<T>>>()?;
+
+        T::primitive_array(env, java_class, &values)
+    }
+}
+
+/// Nested arrays are boxed arrays, like `Integer[][]` for `JavaArray<JavaArray<i32>>`
+impl<T: JavaArrayElement> JavaArrayElement for JavaArray<T> {
+    fn java_type() -> JavaTypeSignature {
+        JavaTypeSignature::array(T::java_type().boxed())
+    }
+}
+
+/// The values of a Java array together with their element type.
+///
+/// `JavaArray` without a type parameter holds any `JavaType` and checks the values against
+/// the element type at runtime, a typed `JavaArray<T>` like `JavaArray<String>` can only hold
+/// values of its element type and is created from a `Vec<T>` or an iterator.
+///
+/// The built array is as long as the values unless a size is set with [`JavaArray::resize`],
+/// then the missing values are taken from the fill value set with [`JavaArray::fill_with`]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct JavaArray<T = JavaType> {
+    size: Option<usize>,
+    java_type: JavaTypeSignature,
+    representation: ArrayRepresentation,
+    values: Vec<T>,
+    fill: Option<Box<T>>,
+}
+
+impl<T> JavaArray<T> {
+    /// Sets the length of the Java array, building fails when the number of values
+    /// does not match unless a fill value is set for the missing ones
+    pub fn resize(mut self, size: usize) -> Self {
+        self.size = Some(size);
+
+        self
+    }
+
+    /// Builds a Java primitive array like `int[]` instead of boxing every element
+    pub fn into_primitive(mut self) -> EasyJNIResult<Self> {
+        if !self.java_type.is_primitive() {
+            return Err(EasyJniError::PrimitiveTypeRequired);
+        }
+
+        self.representation = ArrayRepresentation::Primitive;
+
+        Ok(self)
+    }
+
+    pub fn values(&self) -> &Vec<T> {
+        self.values.as_ref()
+    }
+
+    /// The number of values added to the array
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    /// The length of the Java array this builds
+    pub fn size(&self) -> usize {
+        self.size.unwrap_or(self.values.len())
+    }
+
+    pub fn java_type(&self) -> &JavaTypeSignature {
+        &self.java_type
+    }
+
+    pub fn representation(&self) -> ArrayRepresentation {
+        self.representation
+    }
+
+    /// The signature of the Java array this builds, elements of boxed arrays
+    /// are boxed so an array of `Int` is `Integer[]` while a primitive one is `int[]`
+    pub fn signature(&self) -> JavaTypeSignature {
+        match self.representation {
+            ArrayRepresentation::Boxed => JavaTypeSignature::array(self.java_type.boxed()),
+            ArrayRepresentation::Primitive => JavaTypeSignature::array(self.java_type.clone()),
+        }
+    }
+}
+
+impl<'local, T: JavaArrayElement> JavaArray<T> {
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    /// The value for every element after the last added value when the array is resized
+    pub fn fill_with(mut self, value: T) -> Self {
+        self.fill = Some(Box::new(value));
+
+        self
+    }
+
+    pub fn build(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<jarray> {
+        Ok(self.to_jni_object(env, java_class)?.into_raw())
+    }
+
+    /// Builds the Java array, primitive arrays are copied straight from the values
+    /// while boxed arrays are built like an untyped `JavaArray`
+    pub fn to_jni_object(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        if self.representation == ArrayRepresentation::Boxed {
+            return JavaArray::<JavaType>::from(self.clone()).to_jni_object(env, java_class);
+        }
+
+        let size = self.size();
+
+        if self.values.len() > size {
+            return Err(EasyJniError::ArrayIndexOutOfBounds { index: size, size });
+        }
+
+        if self.values.len() == size {
+            return T::primitive_array(env, java_class, &self.values);
+        }
+
+        let fill = self
+            .fill
+            .as_deref()
+            .ok_or(EasyJniError::MissingArrayValue {
+                index: self.values.len(),
+                size,
+            })?;
+
+        let mut values = self.values.clone();
+        values.resize(size, fill.clone());
+
+        T::primitive_array(env, java_class, &values)
+    }
+}
+
+impl<T: JavaArrayElement> Default for JavaArray<T> {
+    fn default() -> Self {
+        JavaArray::from(Vec::new())
+    }
+}
+
+/// An empty array of `java/lang/Object`
+impl Default for JavaArray {
+    fn default() -> Self {
+        JavaArray::new(JavaTypeSignature::object("java/lang/Object"))
+    }
+}
+
+impl<'local> JavaArray {
+    pub fn new(java_type: JavaTypeSignature) -> Self {
+        JavaArray {
+            size: None,
+            java_type,
+            representation: ArrayRepresentation::Boxed,
+            values: Vec::default(),
+            fill: None,
+        }
+    }
+
+    /// Creates an array that builds a Java primitive array like `int[]`
+    /// instead of boxing every element
+    pub fn new_primitive(java_type: JavaTypeSignature) -> EasyJNIResult<Self> {
+        if !java_type.is_primitive() {
+            return Err(EasyJniError::PrimitiveTypeRequired);
+        }
+
+        JavaArray::new(java_type).into_primitive()
+    }
+
+    /// The value for every element after the last added value when the array is resized,
+    /// use `JavaType::Null` to fill a boxed array with `null`
+    pub fn fill_with(mut self, value: JavaType) -> EasyJNIResult<Self> {
+        if !self.accepts(&value) {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        self.fill = Some(Box::new(value));
+
+        Ok(self)
+    }
+
+    /// Adds a value to the array, `JavaType::Null` is accepted for every element type
+    /// of a boxed array since the elements of object arrays are references.
+    /// Values for reference element types are checked when the array is built,
+    /// primitives are boxed so an `Int` fits an array of `java/lang/Number`
+    pub fn add_value(mut self, value: JavaType) -> EasyJNIResult<Self> {
+        if !self.accepts(&value) {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        self.values.push(value);
+
+        Ok(self)
+    }
+
+    /// Whether `value` can be stored in the array. Subclasses are only known once the value
+    /// is a Java object, so values of reference element types are checked against the
+    /// element class with `IsInstanceOf` when the array is built
+    fn accepts(&self, value: &JavaType) -> bool {
+        match value {
+            JavaType::Void => false,
+            JavaType::Null => self.representation == ArrayRepresentation::Boxed,
+            _ if self.java_type.is_primitive() => value.to_java_type_signature() == self.java_type,
+            _ => true,
+        }
+    }
+
+    pub fn create(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        java_type: JavaTypeSignature,
+        size: usize,
+    ) -> EasyJNIResult<JObjectArray<'local>> {
+        let java_signature = java_type.java_class_name();
+
+        let jarray = env.new_object_array(size as jsize, java_signature, JObject::null())?;
+
+        Ok(jarray)
+    }
+
+    /// Reads the length and elements of a Java array like `String[]`, `Integer[]` or `int[]`.
+    /// Primitive element types accept both primitive and boxed arrays. The JVM checks that
+    /// the array can hold `java_type`, so subclasses of the element type are accepted,
+    /// every element is converted with [`crate::to_rust`]
+    #[cfg(feature = "conversion")]
+    pub fn from_java(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        array: &JObject<'local>,
+        java_type: JavaTypeSignature,
+    ) -> EasyJNIResult<Self> {
+        let java_type = java_type.unboxed();
+
+        if java_type == JavaTypeSignature::Void {
+            return Err(EasyJniError::ArrayOfVoidNotAllowed);
+        }
+
+        // `IsInstanceOf` is true for `null`
+        if array.is_null() {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        let primitive = JavaTypeSignature::array(java_type.clone());
+        let boxed = JavaTypeSignature::array(java_type.boxed());
+
+        if java_type.is_primitive() && env.is_instance_of(array, primitive.java_class_name())? {
+            let values = match java_type {
+                JavaTypeSignature::Byte => Self::read_primitive_array::<jbyte>(env, array),
+                JavaTypeSignature::Short => Self::read_primitive_array::<jshort>(env, array),
+                JavaTypeSignature::Int => Self::read_primitive_array::<jint>(env, array),
+                JavaTypeSignature::Long => Self::read_primitive_array::<jlong>(env, array),
+                JavaTypeSignature::Float => Self::read_primitive_array::<jfloat>(env, array),
+                JavaTypeSignature::Double => Self::read_primitive_array::<jdouble>(env, array),
+                JavaTypeSignature::Boolean => Self::read_primitive_array::<jboolean>(env, array),
+                JavaTypeSignature::Char => Self::read_primitive_array::<jchar>(env, array),
+                _ => Err(EasyJniError::PrimitiveTypeRequired),
+            }?;
+
+            return Ok(JavaArray {
+                size: Some(values.len()),
+                values,
+                ..JavaArray::new_primitive(java_type)?
+            });
+        }
+
+        if !env.is_instance_of(array, boxed.java_class_name())? {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        let array = <&JObjectArray>::from(array);
+        let length = env.get_array_length(array)?;
+        let mut values = Vec::with_capacity(length as usize);
+
+        for index in 0..length {
+            let element = env.get_object_array_element(array, index)?;
+            values.push(crate::to_rust(env, java_class, (&element).into())?);
+            env.delete_local_ref(element)?;
+        }
+
+        Ok(JavaArray {
+            size: Some(values.len()),
+            values,
+            ..JavaArray::new(java_type)
+        })
+    }
+
+    /// Creates a Java primitive array like `int[]` with every element set to zero
+    pub fn create_primitive<T: JavaPrimitive>(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        size: usize,
+    ) -> EasyJNIResult<JPrimitiveArray<'local, T>> {
+        Ok(T::new_array(env, size as jsize)?)
+    }
+
+    /// Creates a Java primitive array holding a copy of `values`
+    pub fn from_slice<T: JavaPrimitive>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        values: &[T],
+    ) -> EasyJNIResult<JPrimitiveArray<'local, T>> {
+        let jarray = JavaArray::create_primitive(env, java_class, values.len())?;
+
+        T::set_region(env, &jarray, 0, values)?;
+
+        Ok(jarray)
+    }
+
+    /// Creates a Java `String[]` holding a copy of `values`, every Java string is released
+    /// once it is stored so large arrays do not overflow the local reference table
+    pub fn from_strings<S: AsRef<str>>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        values: &[S],
+    ) -> EasyJNIResult<JObjectArray<'local>> {
+        let jarray = JavaArray::create(env, java_class, JavaTypeSignature::String, values.len())?;
+
+        for (index, value) in values.iter().enumerate() {
+            let jstring = env.new_string(value.as_ref())?;
+            env.set_object_array_element(&jarray, index as jsize, &jstring)?;
+            env.delete_local_ref(jstring)?;
+        }
+
+        Ok(jarray)
+    }
+
+    /// Copies every element of a Java `String[]` into a `Vec`, a `null` element
+    /// fails with `ArrayElementMismatch` naming its index
+    pub fn to_strings(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        array: &JObjectArray<'local>,
+    ) -> EasyJNIResult<Vec<String>> {
+        let signature = JavaTypeSignature::array(JavaTypeSignature::String);
+
+        if array.is_null() || !env.is_instance_of(array, signature.java_class_name())? {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        let length = env.get_array_length(array)?;
+        let mut values = Vec::with_capacity(length as usize);
+
+        for index in 0..length {
+            let element = JString::from(env.get_object_array_element(array, index)?);
+
+            if element.is_null() {
+                return Err(EasyJniError::ArrayElementMismatch {
+                    index: index as usize,
+                });
+            }
+
+            // Safety: the array was checked to be a `String[]` above
+            values.push(unsafe { env.get_string_unchecked(&element)? }.into());
+            env.delete_local_ref(element)?;
+        }
+
+        Ok(values)
+    }
+
+    /// Copies every element of a Java primitive array into a `Vec`
+    pub fn to_vec<T: JavaPrimitive>(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        array: &JPrimitiveArray<'local, T>,
+    ) -> EasyJNIResult<Vec<T>> {
+        let length = env.get_array_length(array)?;
+
+        let mut values = vec![T::default(); length as usize];
+        T::get_region(env, array, 0, &mut values)?;
+
+        Ok(values)
+    }
+
+    pub fn build(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<jarray> {
+        Ok(self.to_jni_object(env, java_class)?.into_raw())
+    }
+
+    /// Builds the Java array, nested arrays are built recursively
+    pub fn to_jni_object(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        if self.representation == ArrayRepresentation::Boxed {
+            return Ok(JObject::from(self.build_object_array(env, java_class)?));
+        }
+
+        match self.java_type {
+            JavaTypeSignature::Byte => self.build_primitive_array::<jbyte>(env, java_class),
+            JavaTypeSignature::Short => self.build_primitive_array::<jshort>(env, java_class),
+            JavaTypeSignature::Int => self.build_primitive_array::<jint>(env, java_class),
+            JavaTypeSignature::Long => self.build_primitive_array::<jlong>(env, java_class),
+            JavaTypeSignature::Float => self.build_primitive_array::<jfloat>(env, java_class),
+            JavaTypeSignature::Double => self.build_primitive_array::<jdouble>(env, java_class),
+            JavaTypeSignature::Boolean => self.build_primitive_array::<jboolean>(env, java_class),
+            JavaTypeSignature::Char => self.build_primitive_array::<jchar>(env, java_class),
+            _ => Err(EasyJniError::PrimitiveTypeRequired),
+        }
+    }
+
+    /// Every element of the Java array, checked against the element type and size
+    fn elements(&self) -> EasyJNIResult<Vec<&JavaType>> {
+        let size = self.size();
+
+        if self.values.len() > size {
+            return Err(EasyJniError::ArrayIndexOutOfBounds { index: size, size });
+        }
+
+        if let Some(index) = self.values.iter().position(|value| !self.accepts(value)) {
+            return Err(EasyJniError::ArrayElementMismatch { index });
+        }
+
+        let mut elements = self.values.iter().collect::<Vec<&JavaType>>();
+
+        if elements.len() < size {
+            let fill = self
+                .fill
+                .as_deref()
+                .ok_or(EasyJniError::MissingArrayValue {
+                    index: elements.len(),
+                    size,
+                })?;
+
+            elements.resize(size, fill);
+        }
+
+        Ok(elements)
+    }
+
+    fn build_object_array(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObjectArray<'local>> {
+        let elements = self.elements()?;
+        let element_type = self.java_type.boxed();
+        let class = env.find_class(element_type.java_class_name())?;
+
+        let jarray = env.new_object_array(elements.len() as jsize, &class, JObject::null())?;
+
+        for (index, element) in elements.into_iter().enumerate() {
+            // Elements of a new array are already `null`
+            if element.is_null() {
+                continue;
+            }
+
+            // Releases every reference created for the element, nested arrays included
+            with_local_frame(env, java_class, 4, |env, java_class| {
+                let object = element.to_jni_object(env, java_class)?;
+
+                if element.to_java_type_signature().boxed() != element_type
+                    && !env.is_instance_of(&object, &class)?
+                {
+                    return Err(EasyJniError::ArrayElementMismatch { index });
+                }
+
+                Ok(env.set_object_array_element(&jarray, index as jint, object)?)
+            })?;
+        }
+
+        env.delete_local_ref(class)?;
+
+        Ok(jarray)
+    }
+
+    #[cfg(feature = "conversion")]
+    fn read_primitive_array<T: JavaPrimitive>(
+        env: &mut JNIEnv<'local>,
+        array: &JObject<'local>,
+    ) -> EasyJNIResult<Vec<JavaType>> {
+        let length = env.get_array_length(<&JPrimitiveArray<T>>::from(array))?;
+
+        let mut values = vec![T::default(); length as usize];
+        T::get_region(env, <&JPrimitiveArray<T>>::from(array), 0, &mut values)?;
+
+        values.into_iter().map(T::to_java_type).collect()
+    }
+
+    fn build_primitive_array<T: JavaPrimitive>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        let values = self
+            .elements()?
+            .into_iter()
+            .map(T::from_java_type)
+            .collect::<EasyJNIResult<Vec<T>>>()?;
+
+        let jarray = JavaArray::create_primitive::<T>(env, java_class, values.len())?;
+        T::set_region(env, &jarray, 0, &values)?;
+
+        Ok(JObject::from(jarray))
+    }
+}
+
+/// Collects values into a boxed array, the element type is the type shared by every
+/// non null value or `java/lang/Object` when they differ
+impl FromIterator<JavaType> for JavaArray {
+    fn from_iter<I: IntoIterator<Item = JavaType>>(iter: I) -> Self {
+        let values = iter.into_iter().collect::<Vec<JavaType>>();
+
+        let mut signatures = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(JavaType::to_java_type_signature);
+
+        let java_type = match signatures.next() {
+            Some(first) if signatures.all(|signature| signature == first) => first,
+            _ => JavaTypeSignature::object("java/lang/Object"),
+        };
+
+        JavaArray {
+            values,
+            ..JavaArray::new(java_type)
+        }
+    }
+}
+
+impl<T: JavaArrayElement> FromIterator<T> for JavaArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        JavaArray::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: JavaArrayElement> From<Vec<T>> for JavaArray<T> {
+    fn from(values: Vec<T>) -> Self {
+        JavaArray {
+            size: None,
+            java_type: T::java_type(),
+            representation: ArrayRepresentation::Boxed,
+            values,
+            fill: None,
+        }
+    }
+}
+
+impl<T: JavaArrayElement> From<JavaArray<T>> for JavaArray {
+    fn from(array: JavaArray<T>) -> Self {
+        JavaArray {
+            size: array.size,
+            java_type: array.java_type,
+            representation: array.representation,
+            values: array.values.into_iter().map(T::into).collect(),
+            fill: array.fill.map(|fill| Box::new((*fill).into())),
+        }
+    }
+}
+
+/// Converts every value to `T`, failing with `JavaTypeMismatch` when the element type
+/// is not the one of `T` and with the index of the first value of another type
+impl<T: JavaArrayElement> TryFrom<JavaArray> for JavaArray<T> {
+    type Error = EasyJniError;
+
+    fn try_from(array: JavaArray) -> Result<Self, Self::Error> {
+        if array.java_type != T::java_type() {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        let values = array
+            .values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                T::try_from(value).map_err(|_| EasyJniError::ArrayElementMismatch { index })
+            })
+            .collect::<EasyJNIResult<Vec<T>>>()?;
+
+        let fill = array
+            .fill
+            .map(|fill| T::try_from(*fill).map(Box::new))
+            .transpose()?;
+
+        Ok(JavaArray {
+            size: array.size,
+            java_type: array.java_type,
+            representation: array.representation,
+            values,
+            fill,
+        })
+    }
+}
+
+impl<T: JavaArrayElement> From<JavaArray<T>> for JavaType {
+    fn from(array: JavaArray<T>) -> Self {
+        JavaType::Array(JavaArray::<JavaType>::from(array))
+    }
+}
+
+impl From<JavaArray> for JavaType {
+    fn from(array: JavaArray) -> Self {
+        JavaType::Array(array)
+    }
+}
+
+impl<T: JavaArrayElement> TryFrom<JavaType> for JavaArray<T> {
+    type Error = EasyJniError;
+
+    fn try_from(value: JavaType) -> Result<Self, Self::Error> {
+        JavaArray::<T>::try_from(JavaArray::<JavaType>::try_from(value)?)
+    }
+}
+
+impl TryFrom<JavaType> for JavaArray {
+    type Error = EasyJniError;
+
+    fn try_from(value: JavaType) -> Result<Self, Self::Error> {
+        match value {
+            JavaType::Array(array) => Ok(array),
+            _ => Err(EasyJniError::JavaTypeMismatch),
+        }
+    }
+}
+
+impl TryFrom<JavaType> for Option<JavaArray> {
+    type Error = EasyJniError;
+
+    fn try_from(value: JavaType) -> Result<Self, Self::Error> {
+        value.into_option().map(JavaArray::try_from).transpose()
+    }
+}
+
+/// Values of an untyped array are checked against the element type when it is built
+impl<T> Extend<T> for JavaArray<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter)
+    }
+}
+
+impl<T> Index<usize> for JavaArray<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<T> IntoIterator for JavaArray<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a JavaArray<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
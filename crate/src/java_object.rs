@@ -0,0 +1,463 @@
+#[cfg(feature = "conversion")]
+use crate::JavaType;
+use crate::{with_local_frame, EasyJNIResult, EasyJniError, JavaTypeSignature, MethodSignature};
+#[cfg(feature = "conversion")]
+use jni::objects::{JValue, JValueOwned};
+use jni::{
+    objects::{GlobalRef, JClass, JObject, JString},
+    JNIEnv,
+};
+use std::cmp::Ordering;
+
+/// A live reference to any Java object together with the name of its class.
+/// The object is held through a global reference so it stays valid across native calls
+/// and threads, the reference is released once the last clone is dropped
+#[derive(Debug, Clone)]
+pub struct JavaObject {
+    class_name: String,
+    reference: GlobalRef,
+}
+
+impl<'local> JavaObject {
+    /// Creates a global reference to `object` and looks up the name of its class
+    pub fn new(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        object: &JObject<'local>,
+    ) -> EasyJNIResult<Self> {
+        let class = env.get_object_class(object)?;
+        let class_name = Self::class_name_of(env, java_class, &class)?;
+        env.delete_local_ref(class)?;
+
+        JavaObject::with_class_name(env, java_class, object, &class_name)
+    }
+
+    /// Creates a global reference to `object` when the name of its class is already known
+    pub fn with_class_name(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        object: &JObject<'local>,
+        class_name: &str,
+    ) -> EasyJNIResult<Self> {
+        Ok(JavaObject {
+            class_name: class_name.replace('.', "/"),
+            reference: env.new_global_ref(object)?,
+        })
+    }
+
+    /// The name of a class in its internal form like `java/util/List`,
+    /// arrays are named by their descriptor like `[Ljava/lang/String;`
+    pub fn class_name_of(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        class: &JClass<'local>,
+    ) -> EasyJNIResult<String> {
+        let descriptor = MethodSignature::new(Vec::new(), JavaTypeSignature::String);
+        let name = env
+            .call_method(class, "getName", descriptor.descriptor(), &[])?
+            .l()?;
+        let name = JString::from(name);
+        // Safety: `Class.getName` returns a `java.lang.String`
+        let class_name: String = unsafe { env.get_string_unchecked(&name)? }.into();
+        env.delete_local_ref(name)?;
+
+        Ok(class_name.replace('.', "/"))
+    }
+
+    pub fn class_name(&self) -> &str {
+        self.class_name.as_str()
+    }
+
+    /// The signature of the class of this object, arrays are resolved into
+    /// `JavaTypeSignature::Array` of their element type
+    pub fn signature(&self) -> JavaTypeSignature {
+        JavaTypeSignature::from_class_name(&self.class_name)
+            .unwrap_or_else(|_| JavaTypeSignature::object(&self.class_name))
+    }
+
+    pub fn as_obj(&self) -> &JObject<'static> {
+        self.reference.as_obj()
+    }
+
+    pub fn global_ref(&self) -> &GlobalRef {
+        &self.reference
+    }
+
+    /// Creates a new local reference to the object that can be returned to Java
+    /// or passed to `jni` functions
+    pub fn to_local(
+        &self,
+        env: &JNIEnv<'local>,
+        _: &JClass<'local>,
+    ) -> EasyJNIResult<JObject<'local>> {
+        Ok(env.new_local_ref(self.as_obj())?)
+    }
+
+    /// Reads the instance field `name`, its type is looked up through reflection
+    /// on the class of the object and its superclasses
+    #[cfg(feature = "conversion")]
+    pub fn field(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaType> {
+        let signature = self
+            .field_signature(env, java_class, name)
+            .map_err(|error| EasyJniError::Field {
+                name: name.to_owned(),
+                error: Box::new(error),
+            })?;
+
+        self.field_as(env, java_class, name, signature)
+    }
+
+    /// Reads the instance field `name` declared with the type `signature`
+    #[cfg(feature = "conversion")]
+    pub fn field_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        signature: JavaTypeSignature,
+    ) -> EasyJNIResult<JavaType> {
+        with_local_frame(env, java_class, 4, |env, java_class| {
+            let value = env.get_field(self.as_obj(), name, signature.java_signature())?;
+
+            crate::to_rust(env, java_class, value.borrow())
+        })
+        .map_err(|error| EasyJniError::Field {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// Calls the instance method `name`, the method descriptor is built from the exact
+    /// types of the arguments and `return_type`. An object argument stands for its own
+    /// class, so methods declaring an interface, a superclass or a nullable parameter
+    /// need [`JavaObject::call_method_as`] or [`JavaObject::call_overloaded_method`].
+    /// `JavaType::Null` arguments fail with `UntypedNullArgument`
+    #[cfg(feature = "conversion")]
+    pub fn call_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+        return_type: JavaTypeSignature,
+    ) -> EasyJNIResult<JavaType> {
+        let signature =
+            MethodSignature::from_typed_arguments(arguments, return_type).map_err(|error| {
+                EasyJniError::Method {
+                    name: name.to_owned(),
+                    error: Box::new(error),
+                }
+            })?;
+
+        self.call_method_as(env, java_class, name, &signature, arguments)
+    }
+
+    /// Calls the instance method `name` choosing the overload the way Java would,
+    /// see [`MethodSignature::resolve_method`]
+    #[cfg(feature = "conversion")]
+    pub fn call_overloaded_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let signature = with_local_frame(env, java_class, 2, |env, java_class| {
+            let class = env.get_object_class(self.as_obj())?;
+
+            MethodSignature::resolve_method(env, java_class, &class, name, false, arguments)
+        })?;
+
+        self.call_method_as(env, java_class, name, &signature, arguments)
+    }
+
+    /// Calls the instance method `name` declared with `signature`
+    #[cfg(feature = "conversion")]
+    pub fn call_method_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        signature: &MethodSignature,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let capacity = arguments.len() as i32 + 2;
+
+        with_local_frame(env, java_class, capacity, |env, java_class| {
+            let arguments = signature.to_jni_arguments(env, java_class, arguments)?;
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.borrow())
+                .collect::<Vec<JValue>>();
+
+            let outcome =
+                env.call_method(self.as_obj(), name, signature.descriptor(), &arguments)?;
+
+            crate::to_rust(env, java_class, outcome.borrow())
+        })
+        .map_err(|error| EasyJniError::Method {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// Calls the implementation of the instance method `name` declared by the class
+    /// `class_name` instead of the override chosen by the class of the object,
+    /// like `super.name(..)` does in Kotlin. The method descriptor is built from
+    /// the types of the arguments and `return_type` as in [`JavaObject::call_method`]
+    #[cfg(feature = "conversion")]
+    pub fn call_nonvirtual_method(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class_name: &str,
+        name: &str,
+        arguments: &[JavaType],
+        return_type: JavaTypeSignature,
+    ) -> EasyJNIResult<JavaType> {
+        let signature =
+            MethodSignature::from_typed_arguments(arguments, return_type).map_err(|error| {
+                EasyJniError::Method {
+                    name: name.to_owned(),
+                    error: Box::new(error),
+                }
+            })?;
+
+        self.call_nonvirtual_method_as(env, java_class, class_name, name, &signature, arguments)
+    }
+
+    /// Calls the implementation of the instance method `name` declared with `signature`
+    /// by the class `class_name`, which must be the class of the object or a superclass
+    #[cfg(feature = "conversion")]
+    pub fn call_nonvirtual_method_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class_name: &str,
+        name: &str,
+        signature: &MethodSignature,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<JavaType> {
+        let capacity = arguments.len() as i32 + 3;
+
+        with_local_frame(env, java_class, capacity, |env, java_class| {
+            // The JVM does not check that the object is an instance of the class
+            let class = self.cast(env, java_class, class_name)?;
+            let class = env.find_class(class.class_name())?;
+            let method = env.get_method_id(&class, name, signature.descriptor())?;
+
+            let arguments = signature.to_jni_arguments(env, java_class, arguments)?;
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.as_jni())
+                .collect::<Vec<_>>();
+
+            let raw_env = env.get_raw();
+            let object = self.as_obj().as_raw();
+            let class = class.as_raw();
+            let method = method.into_raw();
+
+            macro_rules! call_nonvirtual {
+                ($function:ident) => {{
+                    // Safety: `method` was looked up on `class` with the descriptor of
+                    // `signature`, the arguments were converted for that descriptor
+                    // and the object was checked to be an instance of `class`
+                    unsafe {
+                        let function = (**raw_env).$function.ok_or(
+                            jni::errors::Error::JNIEnvMethodNotFound(stringify!($function)),
+                        )?;
+
+                        function(raw_env, object, class, method, arguments.as_ptr())
+                    }
+                }};
+            }
+
+            let outcome = match signature.return_type() {
+                JavaTypeSignature::Byte => {
+                    JValueOwned::Byte(call_nonvirtual!(CallNonvirtualByteMethodA))
+                }
+                JavaTypeSignature::Short => {
+                    JValueOwned::Short(call_nonvirtual!(CallNonvirtualShortMethodA))
+                }
+                JavaTypeSignature::Int => {
+                    JValueOwned::Int(call_nonvirtual!(CallNonvirtualIntMethodA))
+                }
+                JavaTypeSignature::Long => {
+                    JValueOwned::Long(call_nonvirtual!(CallNonvirtualLongMethodA))
+                }
+                JavaTypeSignature::Float => {
+                    JValueOwned::Float(call_nonvirtual!(CallNonvirtualFloatMethodA))
+                }
+                JavaTypeSignature::Double => {
+                    JValueOwned::Double(call_nonvirtual!(CallNonvirtualDoubleMethodA))
+                }
+                JavaTypeSignature::Boolean => {
+                    JValueOwned::Bool(call_nonvirtual!(CallNonvirtualBooleanMethodA))
+                }
+                JavaTypeSignature::Char => {
+                    JValueOwned::Char(call_nonvirtual!(CallNonvirtualCharMethodA))
+                }
+                JavaTypeSignature::Void => {
+                    call_nonvirtual!(CallNonvirtualVoidMethodA);
+                    JValueOwned::Void
+                }
+                JavaTypeSignature::String
+                | JavaTypeSignature::Object(_)
+                | JavaTypeSignature::Array(_) => {
+                    let object = call_nonvirtual!(CallNonvirtualObjectMethodA);
+
+                    // Safety: the method returns a new local reference or `null`
+                    JValueOwned::Object(unsafe { JObject::from_raw(object) })
+                }
+            };
+
+            if env.exception_check()? {
+                return Err(jni::errors::Error::JavaException.into());
+            }
+
+            crate::to_rust(env, java_class, outcome.borrow())
+        })
+        .map_err(|error| EasyJniError::Method {
+            name: name.to_owned(),
+            error: Box::new(error),
+        })
+    }
+
+    /// Whether the object is an instance of the class `class_name`,
+    /// like `instanceof` in Java or `is` in Kotlin
+    pub fn is_instance_of(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class_name: &str,
+    ) -> EasyJNIResult<bool> {
+        let class_name = class_name.replace('.', "/");
+
+        with_local_frame(env, java_class, 2, |env, _| {
+            let class = env.find_class(class_name)?;
+
+            Ok(env.is_instance_of(self.as_obj(), &class)?)
+        })
+    }
+
+    /// A checked downcast to the class `class_name`, the returned object shares
+    /// the same reference. Fails with [`EasyJniError::InvalidCast`] when the object
+    /// is not an instance of the class
+    pub fn cast(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class_name: &str,
+    ) -> EasyJNIResult<JavaObject> {
+        let class_name = class_name.replace('.', "/");
+
+        if !self.is_instance_of(env, java_class, &class_name)? {
+            return Err(EasyJniError::InvalidCast {
+                class_name: self.class_name.clone(),
+                target: class_name,
+            });
+        }
+
+        Ok(JavaObject {
+            class_name,
+            reference: self.reference.clone(),
+        })
+    }
+
+    #[cfg(feature = "conversion")]
+    fn field_signature(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaTypeSignature> {
+        with_local_frame(env, java_class, 2, |env, java_class| {
+            let class = env.get_object_class(self.as_obj())?;
+
+            Self::declared_field_type(env, java_class, &class, name)
+        })
+    }
+
+    /// The declared type of the field `name` of `class`, found by walking up
+    /// the class hierarchy with `Class.getDeclaredField`
+    #[cfg(feature = "conversion")]
+    pub(crate) fn declared_field_type(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: &JClass<'local>,
+        name: &str,
+    ) -> EasyJNIResult<JavaTypeSignature> {
+        with_local_frame(env, java_class, 8, |env, java_class| {
+            let field_name = JObject::from(env.new_string(name)?);
+            let get_declared_field = MethodSignature::new(
+                vec![JavaTypeSignature::String],
+                JavaTypeSignature::object("java/lang/reflect/Field"),
+            );
+            let get_type =
+                MethodSignature::new(Vec::new(), JavaTypeSignature::object("java/lang/Class"));
+
+            let mut class = Some(JClass::from(env.new_local_ref(class)?));
+
+            while let Some(current) = class {
+                let field = env.call_method(
+                    &current,
+                    "getDeclaredField",
+                    get_declared_field.descriptor(),
+                    &[JValue::from(&field_name)],
+                );
+
+                match field {
+                    Ok(field) => {
+                        let field_type = env
+                            .call_method(field.l()?, "getType", get_type.descriptor(), &[])?
+                            .l()?;
+                        let type_name =
+                            Self::class_name_of(env, java_class, &JClass::from(field_type))?;
+
+                        return JavaTypeSignature::from_class_name(&type_name);
+                    }
+                    Err(jni::errors::Error::JavaException) => {
+                        let exception = env.exception_occurred()?;
+                        env.exception_clear()?;
+
+                        // Other exceptions like a `SecurityException` are thrown again
+                        if !env.is_instance_of(&exception, "java/lang/NoSuchFieldException")? {
+                            env.throw(exception)?;
+
+                            return Err(jni::errors::Error::JavaException.into());
+                        }
+
+                        // The field may be declared by a superclass
+                        class = env.get_superclass(&current)?;
+                    }
+                    Err(error) => return Err(error.into()),
+                }
+            }
+
+            Err(EasyJniError::FieldNotFound)
+        })
+    }
+}
+
+/// Two objects are equal when they are held through the same reference,
+/// use `JNIEnv::is_same_object` to compare the Java objects themselves
+impl PartialEq for JavaObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.class_name == other.class_name && self.as_obj().as_raw() == other.as_obj().as_raw()
+    }
+}
+
+impl PartialOrd for JavaObject {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.class_name.cmp(&other.class_name) {
+            Ordering::Equal if self == other => Some(Ordering::Equal),
+            Ordering::Equal => None,
+            ordering => Some(ordering),
+        }
+    }
+}
@@ -1,14 +1,17 @@
-use crate::{EasyJNIResult, EasyJniError};
+use crate::{
+    DirectByteBuffer, EasyJNIResult, EasyJniError, JavaArray, JavaObject, MethodSignature,
+};
 use jni::{
     objects::{JClass, JObject, JString, JValueOwned},
     JNIEnv,
 };
 
-/// The eight primitive types of java
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+/// A Java value: one of the eight primitive types of java, `void`, a `String`,
+/// any other object, `null`, an array or a direct `ByteBuffer`
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
 pub enum JavaType {
     /// an 8-bit signed two's complement integer, ranging from -128 to 127
-    Byte(u8),
+    Byte(i8),
     /// a 16-bit signed two's complement integer, ranging from -32,768 to 32,767
     Short(i16),
     /// a 32-bit signed two's complement integer, ranging from -2,147,483,648 to 2,147,483,647
@@ -24,15 +27,18 @@ pub enum JavaType {
     /// a single 16-bit Unicode character, representing a wide range of characters from different languages and scripts.
     Char(char),
     /// Returns nothing
+    #[default]
     Void,
     /// A String
     String(String),
-}
-
-impl Default for JavaType {
-    fn default() -> Self {
-        JavaType::Void
-    }
+    /// Any other Java object held through a global reference
+    Object(JavaObject),
+    /// A `null` reference, Kotlin nullable types map `null` to this and `Option<T>` maps `None` to this
+    Null,
+    /// An array, which can itself hold arrays
+    Array(JavaArray),
+    /// Bytes shared with Java through a direct `java.nio.ByteBuffer`
+    ByteBuffer(DirectByteBuffer),
 }
 
 impl<'local> JavaType {
@@ -41,7 +47,7 @@ impl<'local> JavaType {
         _: &JClass<'local>,
         value: &str,
     ) -> EasyJNIResult<JString<'local>> {
-        Ok(env.new_string(&value)?)
+        Ok(env.new_string(value)?)
     }
 
     pub fn new_jvalue_string(
@@ -49,70 +55,227 @@ impl<'local> JavaType {
         _: &JClass<'local>,
         value: &str,
     ) -> EasyJNIResult<JValueOwned<'local>> {
-        let string = env.new_string(&value)?;
+        let string = env.new_string(value)?;
 
-        Ok(JValueOwned::from(JString::from(string)))
+        Ok(JValueOwned::from(string))
     }
 
     pub fn to_jni_jvalue(
         &self,
-        env: &JNIEnv<'local>,
-        _: &JClass<'local>,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
     ) -> EasyJNIResult<JValueOwned<'local>> {
         let outcome = match self {
-            Self::Byte(byte_value) => JValueOwned::from(*byte_value as i8),
+            Self::Byte(byte_value) => JValueOwned::from(*byte_value),
             Self::Short(short_value) => JValueOwned::from(*short_value),
             Self::Int(int_value) => JValueOwned::from(*int_value),
             Self::Long(long_value) => JValueOwned::from(*long_value),
             Self::Float(float_value) => JValueOwned::from(*float_value),
             Self::Double(double_value) => JValueOwned::from(*double_value),
             Self::Boolean(bool_value) => JValueOwned::from(*bool_value),
+            Self::Char(char_value) => JValueOwned::Char(Self::char_to_utf16(*char_value)?),
             Self::Void => JValueOwned::Void,
             Self::String(string_value) => JValueOwned::from(env.new_string(string_value)?),
-            _ => return Err(EasyJniError::UnsupportedJavaType),
+            Self::Object(object_value) => {
+                JValueOwned::from(env.new_local_ref(object_value.as_obj())?)
+            }
+            Self::Null => JValueOwned::Object(JObject::null()),
+            Self::Array(array_value) => {
+                JValueOwned::from(array_value.to_jni_object(env, java_class)?)
+            }
+            Self::ByteBuffer(buffer_value) => {
+                JValueOwned::from(buffer_value.to_jni_object(env, java_class)?)
+            }
         };
 
         Ok(outcome)
     }
 
+    /// Creates a Java byte from an unsigned byte keeping the same bits,
+    /// so `255u8` becomes `-1i8`
+    pub fn from_unsigned_byte(value: u8) -> Self {
+        JavaType::Byte(value as i8)
+    }
+
+    /// The unsigned view of a Java byte keeping the same bits, so `-1i8` becomes `255u8`.
+    /// Returns `None` if the value is not a `JavaType::Byte`
+    pub fn as_unsigned_byte(&self) -> Option<u8> {
+        match self {
+            JavaType::Byte(value) => Some(*value as u8),
+            _ => None,
+        }
+    }
+
+    /// Converts a Rust `char` into a Java `char` which is a single UTF-16 code unit.
+    /// Characters outside the Basic Multilingual Plane need a surrogate pair
+    /// and cannot be represented by one Java `char`
+    pub fn char_to_utf16(value: char) -> EasyJNIResult<u16> {
+        u16::try_from(value as u32).map_err(|_| EasyJniError::CharOutsideBmp(value))
+    }
+
+    /// Converts a Java `char` into a Rust `char`. A lone surrogate
+    /// is only valid as part of a pair and has no Rust `char` equivalent
+    pub fn char_from_utf16(value: u16) -> EasyJNIResult<char> {
+        char::from_u32(value as u32).ok_or(EasyJniError::LoneSurrogate(value))
+    }
+
+    /// Converts a primitive to the primitive type `target` through a widening primitive
+    /// conversion like `int` to `long`, `None` when Java does not allow the conversion
+    pub fn widen(&self, target: &JavaTypeSignature) -> Option<JavaType> {
+        let value = match (self, target) {
+            (_, _) if self.to_java_type_signature() == *target => self.clone(),
+            (_, _) if !self.to_java_type_signature().widens_to(target) => return None,
+            (Self::Byte(value), JavaTypeSignature::Short) => Self::Short(*value as i16),
+            (Self::Byte(value), JavaTypeSignature::Int) => Self::Int(*value as i32),
+            (Self::Byte(value), JavaTypeSignature::Long) => Self::Long(*value as i64),
+            (Self::Byte(value), JavaTypeSignature::Float) => Self::Float(*value as f32),
+            (Self::Byte(value), JavaTypeSignature::Double) => Self::Double(*value as f64),
+            (Self::Short(value), JavaTypeSignature::Int) => Self::Int(*value as i32),
+            (Self::Short(value), JavaTypeSignature::Long) => Self::Long(*value as i64),
+            (Self::Short(value), JavaTypeSignature::Float) => Self::Float(*value as f32),
+            (Self::Short(value), JavaTypeSignature::Double) => Self::Double(*value as f64),
+            (Self::Char(value), _) => {
+                Self::Int(Self::char_to_utf16(*value).ok()? as i32).widen(target)?
+            }
+            (Self::Int(value), JavaTypeSignature::Long) => Self::Long(*value as i64),
+            (Self::Int(value), JavaTypeSignature::Float) => Self::Float(*value as f32),
+            (Self::Int(value), JavaTypeSignature::Double) => Self::Double(*value as f64),
+            (Self::Long(value), JavaTypeSignature::Float) => Self::Float(*value as f32),
+            (Self::Long(value), JavaTypeSignature::Double) => Self::Double(*value as f64),
+            (Self::Float(value), JavaTypeSignature::Double) => Self::Double(*value as f64),
+            _ => return None,
+        };
+
+        Some(value)
+    }
+
+    /// Converts the value for a parameter or field declared with the type `signature`,
+    /// primitives are widened to the declared primitive type like `int` to `long`
+    /// and boxed when the declared type is a reference type, boxed objects like
+    /// `java.lang.Integer` are unboxed when the declared type is a primitive type.
+    /// Fails with `JavaTypeMismatch` when the value cannot be assigned to that type,
+    /// since JNI stores any object in any reference field without a check
+    pub fn to_jni_jvalue_as(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        signature: &JavaTypeSignature,
+    ) -> EasyJNIResult<JValueOwned<'local>> {
+        if signature.is_primitive() {
+            if let Self::Object(object) = self {
+                return Self::unbox_to(env, java_class, object, signature);
+            }
+
+            return self
+                .widen(signature)
+                .ok_or(EasyJniError::JavaTypeMismatch)?
+                .to_jni_jvalue(env, java_class);
+        }
+
+        let object = self.to_jni_object(env, java_class)?;
+
+        if self.is_null() || self.to_java_type_signature().boxed() == *signature {
+            return Ok(JValueOwned::Object(object));
+        }
+
+        let class = env.find_class(signature.java_class_name())?;
+        let assignable = env.is_instance_of(&object, &class)?;
+        env.delete_local_ref(class)?;
+
+        if !assignable {
+            env.delete_local_ref(object)?;
+
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        Ok(JValueOwned::Object(object))
+    }
+
+    /// Unboxes `object` for the primitive type `signature`, followed by a widening
+    /// primitive conversion like `java.lang.Integer` to `long`. The numeric boxes
+    /// extend `java.lang.Number` whose `longValue` and the like widen as Java does
+    fn unbox_to(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        object: &JavaObject,
+        signature: &JavaTypeSignature,
+    ) -> EasyJNIResult<JValueOwned<'local>> {
+        let unboxed = object.signature().unboxed();
+
+        if !unboxed.widens_to(signature) {
+            return Err(EasyJniError::JavaTypeMismatch);
+        }
+
+        // `java.lang.Character` is no `Number`, its `char` is widened once read
+        let read_as = if unboxed == JavaTypeSignature::Char {
+            &unboxed
+        } else {
+            signature
+        };
+        let method = read_as
+            .unboxing_method()
+            .ok_or(EasyJniError::JavaTypeMismatch)?;
+        let descriptor = MethodSignature::new(Vec::new(), read_as.clone());
+        let value = env.call_method(object.as_obj(), method, descriptor.descriptor(), &[])?;
+
+        match value {
+            JValueOwned::Char(value) if *signature != JavaTypeSignature::Char => {
+                Self::Int(value as i32)
+                    .widen(signature)
+                    .ok_or(EasyJniError::JavaTypeMismatch)?
+                    .to_jni_jvalue(env, java_class)
+            }
+            value => Ok(value),
+        }
+    }
+
+    /// Converts the value into a Java object, boxing primitives through their
+    /// `valueOf` method, for example `JavaType::Int(1)` becomes `java.lang.Integer`
     pub fn to_jni_object(
-        &'local self,
+        &self,
         env: &mut JNIEnv<'local>,
-        java_class: &'local JClass<'local>,
+        java_class: &JClass<'local>,
     ) -> EasyJNIResult<JObject<'local>> {
-        let class = self.java_class(env, &java_class)?;
+        let object = match self {
+            Self::Void => return Err(EasyJniError::UnsupportedJavaType),
+            Self::String(string_value) => JObject::from(env.new_string(string_value)?),
+            Self::Object(object_value) => env.new_local_ref(object_value.as_obj())?,
+            Self::Null => JObject::null(),
+            Self::Array(array_value) => array_value.to_jni_object(env, java_class)?,
+            Self::ByteBuffer(buffer_value) => buffer_value.to_jni_object(env, java_class)?,
+            _ => {
+                let java_type = self.to_java_type_signature();
+                let descriptor = MethodSignature::new(vec![java_type.clone()], java_type.boxed());
+                let value = self.to_jni_jvalue(env, java_class)?;
 
-        let object = env.alloc_object(class)?;
+                env.call_static_method(
+                    java_type.java_class_name(),
+                    "valueOf",
+                    descriptor.descriptor(),
+                    &[value.borrow()],
+                )?
+                .l()?
+            }
+        };
 
         Ok(object)
     }
 
     pub fn java_class(
-        &'local self,
+        &self,
         env: &mut JNIEnv<'local>,
         _: &JClass<'local>,
-    ) -> EasyJNIResult<JClass> {
+    ) -> EasyJNIResult<JClass<'local>> {
         // Get a reference to the Integer class
         Ok(env.find_class(self.java_class_name())?)
     }
 
-    pub fn java_signature(&self) -> &str {
-        match self {
-            Self::Byte { .. } => JavaTypeSignature::Byte.java_signature(),
-            Self::Short { .. } => JavaTypeSignature::Short.java_signature(),
-            Self::Int { .. } => JavaTypeSignature::Int.java_signature(),
-            Self::Long { .. } => JavaTypeSignature::Long.java_signature(),
-            Self::Float { .. } => JavaTypeSignature::Float.java_signature(),
-            Self::Double { .. } => JavaTypeSignature::Double.java_signature(),
-            Self::Boolean { .. } => JavaTypeSignature::Boolean.java_signature(),
-            Self::Char { .. } => JavaTypeSignature::Char.java_signature(),
-            Self::Void { .. } => JavaTypeSignature::Void.java_signature(),
-            Self::String { .. } => JavaTypeSignature::String.java_signature(),
-        }
+    pub fn java_signature(&self) -> String {
+        self.to_java_type_signature().java_signature()
     }
 
     pub fn java_class_name(&self) -> String {
-        self.to_java_type_signature().java_class_name().to_owned()
+        self.to_java_type_signature().java_class_name()
     }
 
     pub fn to_java_type_signature(&self) -> JavaTypeSignature {
@@ -127,11 +290,27 @@ impl<'local> JavaType {
             JavaType::Char(_) => JavaTypeSignature::Char,
             JavaType::Void => JavaTypeSignature::Void,
             JavaType::String(_) => JavaTypeSignature::String,
+            JavaType::Object(object_value) => object_value.signature(),
+            JavaType::Null => JavaTypeSignature::object("java/lang/Object"),
+            JavaType::Array(array_value) => array_value.signature(),
+            JavaType::ByteBuffer(_) => DirectByteBuffer::signature(),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, JavaType::Null)
+    }
+
+    /// Maps `JavaType::Null` to `None` and any other value to `Some`
+    pub fn into_option(self) -> Option<JavaType> {
+        match self {
+            JavaType::Null => None,
+            value => Some(value),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Default, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Default, Eq, PartialOrd, Ord, Clone)]
 pub enum JavaTypeSignature {
     Byte,
     Short,
@@ -144,61 +323,263 @@ pub enum JavaTypeSignature {
     #[default]
     Void,
     String,
+    /// Any other class in its internal form like `java/util/List`
+    Object(String),
+    /// An array of any dimension, `String[][]` is an array of arrays of `String`
+    Array(Box<JavaTypeSignature>),
 }
 
 impl JavaTypeSignature {
-    pub fn java_signature(&self) -> &str {
+    /// Creates the signature of a class from either its binary name `java.util.List`
+    /// or its internal form `java/util/List`
+    pub fn object(class_name: &str) -> Self {
+        let class_name = class_name.replace('.', "/");
+
+        if class_name == Self::String.java_class_name() {
+            Self::String
+        } else {
+            Self::Object(class_name)
+        }
+    }
+
+    /// Creates a signature from a name returned by `java.lang.Class.getName()`
+    /// like `int`, `java.lang.String` or `[Ljava.lang.String;`
+    pub fn from_class_name(class_name: &str) -> EasyJNIResult<Self> {
+        let java_type = match class_name {
+            "byte" => Self::Byte,
+            "short" => Self::Short,
+            "int" => Self::Int,
+            "long" => Self::Long,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "boolean" => Self::Boolean,
+            "char" => Self::Char,
+            "void" => Self::Void,
+            _ if class_name.starts_with('[') => Self::parse(&class_name.replace('.', "/"))?,
+            _ => Self::object(class_name),
+        };
+
+        Ok(java_type)
+    }
+
+    /// Creates the signature of an array whose elements are `element`
+    pub fn array(element: JavaTypeSignature) -> Self {
+        Self::Array(Box::new(element))
+    }
+
+    /// The JNI type descriptor used for fields and method parameters,
+    /// for example `I`, `Ljava/lang/String;` or `[[D`
+    pub fn java_signature(&self) -> String {
         match self {
-            Self::Byte => "B",
-            Self::Short => "S",
-            Self::Int => "I",
-            Self::Long => "J",
-            Self::Float => "F",
-            Self::Double => "D",
-            Self::Boolean => "Z",
-            Self::Char => "C",
-            Self::Void => "V",
-            Self::String => "java/lang/String",
+            Self::Byte => "B".to_owned(),
+            Self::Short => "S".to_owned(),
+            Self::Int => "I".to_owned(),
+            Self::Long => "J".to_owned(),
+            Self::Float => "F".to_owned(),
+            Self::Double => "D".to_owned(),
+            Self::Boolean => "Z".to_owned(),
+            Self::Char => "C".to_owned(),
+            Self::Void => "V".to_owned(),
+            Self::String | Self::Object(_) => "L".to_owned() + &self.java_class_name() + ";",
+            Self::Array(element) => "[".to_owned() + &element.java_signature(),
         }
     }
 
-    pub fn java_class_name(&self) -> &str {
+    /// The class name as expected by `FindClass`. Primitives return their boxed class
+    /// and arrays return their descriptor, for example `[Ljava/lang/String;`
+    pub fn java_class_name(&self) -> String {
         match self {
-            Self::Byte => "java/lang/Byte",
-            Self::Short => "java/lang/Short",
-            Self::Int => "java/lang/Integer",
-            Self::Long => "java/lang/Long",
-            Self::Float => "java/lang/Float",
-            Self::Double => "java/lang/Double",
-            Self::Boolean => "java/lang/Boolean",
-            Self::Char => "java/lang/Character",
-            Self::Void => "java/lang/Void",
-            Self::String => "java/lang/String",
+            Self::Byte => "java/lang/Byte".to_owned(),
+            Self::Short => "java/lang/Short".to_owned(),
+            Self::Int => "java/lang/Integer".to_owned(),
+            Self::Long => "java/lang/Long".to_owned(),
+            Self::Float => "java/lang/Float".to_owned(),
+            Self::Double => "java/lang/Double".to_owned(),
+            Self::Boolean => "java/lang/Boolean".to_owned(),
+            Self::Char => "java/lang/Character".to_owned(),
+            Self::Void => "java/lang/Void".to_owned(),
+            Self::String => "java/lang/String".to_owned(),
+            Self::Object(class_name) => class_name.clone(),
+            Self::Array(_) => self.java_signature(),
         }
     }
 
+    /// The descriptor of an object array holding this type,
+    /// primitives are boxed so `Int` gives `[Ljava/lang/Integer;`
     pub fn java_class_name_array(&self) -> EasyJNIResult<String> {
         let class_name = match self {
             Self::Void => return Err(EasyJniError::ArrayOfVoidNotAllowed),
-            _ => "[".to_owned() + self.bytecode_signature().as_str(),
+            _ => "[".to_owned() + self.boxed().java_signature().as_str(),
         };
 
         Ok(class_name)
     }
 
+    /// The JNI type descriptor, same as [`JavaTypeSignature::java_signature`]
     pub fn bytecode_signature(&self) -> String {
-        "L".to_owned() + self.java_class_name() + ";"
+        self.java_signature()
+    }
+
+    /// The signature of the boxed class for primitives like `java/lang/Integer` for `Int`,
+    /// any other type is returned as is
+    pub fn boxed(&self) -> JavaTypeSignature {
+        if self.is_primitive() {
+            Self::Object(self.java_class_name())
+        } else {
+            self.clone()
+        }
     }
 
+    /// The primitive type of a boxed class like `Int` for `java/lang/Integer`,
+    /// any other type is returned as is
+    pub fn unboxed(&self) -> JavaTypeSignature {
+        let Self::Object(class_name) = self else {
+            return self.clone();
+        };
+
+        match class_name.as_str() {
+            "java/lang/Byte" => Self::Byte,
+            "java/lang/Short" => Self::Short,
+            "java/lang/Integer" => Self::Int,
+            "java/lang/Long" => Self::Long,
+            "java/lang/Float" => Self::Float,
+            "java/lang/Double" => Self::Double,
+            "java/lang/Boolean" => Self::Boolean,
+            "java/lang/Character" => Self::Char,
+            _ => self.clone(),
+        }
+    }
+
+    /// Whether a value of this primitive type can be passed where `target` is expected,
+    /// either because the types are the same or through a widening primitive conversion
+    pub fn widens_to(&self, target: &JavaTypeSignature) -> bool {
+        if self == target {
+            return self.is_primitive();
+        }
+
+        matches!(
+            (self, target),
+            (
+                Self::Byte,
+                Self::Short | Self::Int | Self::Long | Self::Float | Self::Double
+            ) | (
+                Self::Short | Self::Char,
+                Self::Int | Self::Long | Self::Float | Self::Double
+            ) | (Self::Int, Self::Long | Self::Float | Self::Double)
+                | (Self::Long, Self::Float | Self::Double)
+                | (Self::Float, Self::Double)
+        )
+    }
+
+    /// Returns `true` for the eight primitive types of java
+    pub fn is_primitive(&self) -> bool {
+        !matches!(
+            self,
+            Self::Void | Self::String | Self::Object(_) | Self::Array(_)
+        )
+    }
+
+    /// The method on the boxed class which returns the primitive value,
+    /// for example `intValue` on `java.lang.Integer`
+    pub fn unboxing_method(&self) -> Option<&str> {
+        let method = match self {
+            Self::Byte => "byteValue",
+            Self::Short => "shortValue",
+            Self::Int => "intValue",
+            Self::Long => "longValue",
+            Self::Float => "floatValue",
+            Self::Double => "doubleValue",
+            Self::Boolean => "booleanValue",
+            Self::Char => "charValue",
+            _ => return None,
+        };
+
+        Some(method)
+    }
+
+    /// The value a Java field of this type holds before it is assigned,
+    /// strings, objects and arrays default to `null`
+    pub fn default_value(&self) -> JavaType {
+        match self {
+            Self::Byte => JavaType::Byte(0),
+            Self::Short => JavaType::Short(0),
+            Self::Int => JavaType::Int(0),
+            Self::Long => JavaType::Long(0),
+            Self::Float => JavaType::Float(0.0),
+            Self::Double => JavaType::Double(0.0),
+            Self::Boolean => JavaType::Boolean(false),
+            Self::Char => JavaType::Char('\0'),
+            Self::Void => JavaType::Void,
+            Self::String | Self::Object(_) | Self::Array(_) => JavaType::Null,
+        }
+    }
+
+    /// Creates a Java object holding the default value of this type,
+    /// primitives are boxed while references are `null`
     pub fn to_jni_object<'local>(
         &self,
         env: &mut JNIEnv<'local>,
-        _: &'local JClass<'local>,
+        java_class: &JClass<'local>,
     ) -> EasyJNIResult<JObject<'local>> {
-        let class = self.java_class_name();
+        self.default_value().to_jni_object(env, java_class)
+    }
+}
 
-        let object = env.alloc_object(class)?;
+impl From<&str> for JavaType {
+    fn from(value: &str) -> Self {
+        JavaType::String(value.to_owned())
+    }
+}
 
-        Ok(object)
+impl<T: Into<JavaType>> From<Option<T>> for JavaType {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(JavaType::Null, Into::into)
     }
 }
+
+/// Converts between Rust values and the matching `JavaType` variant,
+/// `Option<T>` maps `None` to `JavaType::Null`
+macro_rules! java_type_conversions {
+    ($($rust_type:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$rust_type> for JavaType {
+                fn from(value: $rust_type) -> Self {
+                    JavaType::$variant(value)
+                }
+            }
+
+            impl TryFrom<JavaType> for $rust_type {
+                type Error = EasyJniError;
+
+                fn try_from(value: JavaType) -> Result<Self, Self::Error> {
+                    match value {
+                        JavaType::$variant(inner_value) => Ok(inner_value),
+                        _ => Err(EasyJniError::JavaTypeMismatch),
+                    }
+                }
+            }
+
+            impl TryFrom<JavaType> for Option<$rust_type> {
+                type Error = EasyJniError;
+
+                fn try_from(value: JavaType) -> Result<Self, Self::Error> {
+                    value.into_option().map(<$rust_type>::try_from).transpose()
+                }
+            }
+        )*
+    };
+}
+
+java_type_conversions!(
+    i8 => Byte,
+    i16 => Short,
+    i32 => Int,
+    i64 => Long,
+    f32 => Float,
+    f64 => Double,
+    bool => Boolean,
+    char => Char,
+    String => String,
+    JavaObject => Object,
+    DirectByteBuffer => ByteBuffer,
+);
@@ -3,6 +3,11 @@ mod classes;
 #[cfg(feature = "complex_types")]
 pub use classes::*;
 
+#[cfg(feature = "simple_types")]
+mod byte_buffer;
+#[cfg(feature = "simple_types")]
+pub use byte_buffer::*;
+
 mod errors;
 pub use errors::*;
 
@@ -16,4 +21,40 @@ mod java_types;
 #[cfg(feature = "simple_types")]
 pub use java_types::*;
 
+#[cfg(feature = "simple_types")]
+mod java_array;
+#[cfg(feature = "simple_types")]
+pub use java_array::*;
+
+#[cfg(feature = "simple_types")]
+mod java_object;
+#[cfg(feature = "simple_types")]
+pub use java_object::*;
+
+#[cfg(feature = "simple_types")]
+mod local_frame;
+#[cfg(feature = "simple_types")]
+pub use local_frame::*;
+
+#[cfg(feature = "simple_types")]
+mod method_signature;
+#[cfg(feature = "simple_types")]
+pub use method_signature::*;
+
+#[cfg(feature = "simple_types")]
+mod overload;
+
+#[cfg(feature = "simple_types")]
+mod parser;
+
+#[cfg(feature = "simple_types")]
+mod pinned;
+#[cfg(feature = "simple_types")]
+pub use pinned::*;
+
+#[cfg(feature = "simple_types")]
+mod primitive_array;
+#[cfg(feature = "simple_types")]
+pub use primitive_array::*;
+
 pub use jni;
@@ -0,0 +1,104 @@
+use crate::EasyJNIResult;
+use jni::{
+    objects::{JClass, JObject},
+    JNIEnv,
+};
+use std::ops::{Deref, DerefMut};
+
+/// A scope for local references created through `PushLocalFrame`, every local reference
+/// created through the frame is released when it is dropped. A single reference can
+/// be kept alive in the enclosing frame with [`LocalFrame::pop`].
+///
+/// References created through the frame keep the `'local` lifetime of the enclosing
+/// `JNIEnv`, so the compiler cannot stop them from being used after the frame is gone.
+/// Prefer `JNIEnv::with_local_frame` when the scope fits in a closure
+pub struct LocalFrame<'frame, 'local> {
+    env: &'frame mut JNIEnv<'local>,
+    popped: bool,
+}
+
+impl<'frame, 'local> LocalFrame<'frame, 'local> {
+    /// Pushes a new frame with room for at least `capacity` local references
+    ///
+    /// # Safety
+    ///
+    /// No local reference created through the frame may be used once the frame is
+    /// dropped or popped, except the one returned by [`LocalFrame::pop`]. The JVM frees
+    /// them and may reuse their slots for unrelated objects
+    pub unsafe fn new(
+        env: &'frame mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        capacity: i32,
+    ) -> EasyJNIResult<Self> {
+        env.push_local_frame(capacity)?;
+
+        Ok(LocalFrame { env, popped: false })
+    }
+
+    /// Releases every local reference of the frame except `result`,
+    /// which is returned as a new local reference in the enclosing frame
+    pub fn pop(mut self, result: &JObject) -> EasyJNIResult<JObject<'local>> {
+        self.popped = true;
+
+        // Safety: the frame was pushed in `new` and is popped only once
+        Ok(unsafe { self.env.pop_local_frame(result)? })
+    }
+}
+
+/// Runs `scope` in a new frame through `JNIEnv::with_local_frame`, with room for at least
+/// `capacity` local references. The class which owns the native method is passed on
+/// as a local reference of the frame, so no reference can escape the closure
+pub(crate) fn with_local_frame<'local, T>(
+    env: &mut JNIEnv<'local>,
+    java_class: &JClass<'local>,
+    capacity: i32,
+    scope: impl for<'frame> FnOnce(&mut JNIEnv<'frame>, &JClass<'frame>) -> EasyJNIResult<T>,
+) -> EasyJNIResult<T> {
+    env.with_local_frame(capacity + 1, |env| {
+        let java_class = JClass::from(env.new_local_ref(java_class)?);
+
+        scope(env, &java_class)
+    })
+}
+
+/// Like [`with_local_frame`], the object returned by `scope` is kept alive
+/// as a new local reference in the enclosing frame
+#[cfg(feature = "complex_types")]
+pub(crate) fn with_local_frame_returning_local<'local>(
+    env: &mut JNIEnv<'local>,
+    java_class: &JClass<'local>,
+    capacity: i32,
+    scope: impl for<'frame> FnOnce(
+        &mut JNIEnv<'frame>,
+        &JClass<'frame>,
+    ) -> EasyJNIResult<JObject<'frame>>,
+) -> EasyJNIResult<JObject<'local>> {
+    env.with_local_frame_returning_local(capacity + 1, |env| {
+        let java_class = JClass::from(env.new_local_ref(java_class)?);
+
+        scope(env, &java_class)
+    })
+}
+
+impl<'frame, 'local> Deref for LocalFrame<'frame, 'local> {
+    type Target = JNIEnv<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        self.env
+    }
+}
+
+impl<'frame, 'local> DerefMut for LocalFrame<'frame, 'local> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.env
+    }
+}
+
+impl<'frame, 'local> Drop for LocalFrame<'frame, 'local> {
+    fn drop(&mut self) {
+        if !self.popped {
+            // Safety: the frame was pushed in `new` and has not been popped yet
+            let _ = unsafe { self.env.pop_local_frame(&JObject::null()) };
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{EasyJNIResult, EasyJniError, JavaType, JavaTypeSignature};
+use jni::{
+    objects::{JClass, JValueOwned},
+    JNIEnv,
+};
+use std::fmt;
+
+/// The signature of a Java method made up of its parameter types and its return type,
+/// rendered as a JNI method descriptor like `(ILjava/lang/String;)V`
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct MethodSignature {
+    parameters: Vec<JavaTypeSignature>,
+    return_type: JavaTypeSignature,
+}
+
+impl MethodSignature {
+    pub fn new(parameters: Vec<JavaTypeSignature>, return_type: JavaTypeSignature) -> Self {
+        MethodSignature {
+            parameters,
+            return_type,
+        }
+    }
+
+    /// The signature of a constructor, which always returns `void`
+    pub fn constructor(parameters: Vec<JavaTypeSignature>) -> Self {
+        MethodSignature::new(parameters, JavaTypeSignature::Void)
+    }
+
+    /// Derives the parameter types from the values that will be passed as arguments
+    pub fn from_arguments(arguments: &[JavaType], return_type: JavaTypeSignature) -> Self {
+        let parameters = arguments
+            .iter()
+            .map(|argument| argument.to_java_type_signature())
+            .collect();
+
+        MethodSignature::new(parameters, return_type)
+    }
+
+    /// Same as [`MethodSignature::from_arguments`] but fails for `JavaType::Null`
+    /// arguments, whose parameter type cannot be derived
+    #[cfg(feature = "conversion")]
+    pub(crate) fn from_typed_arguments(
+        arguments: &[JavaType],
+        return_type: JavaTypeSignature,
+    ) -> EasyJNIResult<Self> {
+        if let Some(index) = arguments.iter().position(JavaType::is_null) {
+            return Err(EasyJniError::UntypedNullArgument { index });
+        }
+
+        Ok(MethodSignature::from_arguments(arguments, return_type))
+    }
+
+    pub fn add_parameter(mut self, parameter: JavaTypeSignature) -> Self {
+        self.parameters.push(parameter);
+
+        self
+    }
+
+    pub fn parameters(&self) -> &[JavaTypeSignature] {
+        self.parameters.as_ref()
+    }
+
+    pub fn return_type(&self) -> &JavaTypeSignature {
+        &self.return_type
+    }
+
+    /// Converts `arguments` for the parameters of this signature,
+    /// primitives are boxed for parameters of reference types
+    pub fn to_jni_arguments<'local>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<Vec<JValueOwned<'local>>> {
+        if arguments.len() != self.parameters.len() {
+            return Err(EasyJniError::ArgumentCountMismatch {
+                expected: self.parameters.len(),
+                found: arguments.len(),
+            });
+        }
+
+        self.parameters
+            .iter()
+            .zip(arguments)
+            .map(|(parameter, argument)| argument.to_jni_jvalue_as(env, java_class, parameter))
+            .collect()
+    }
+
+    /// The JNI method descriptor, for example `([Ljava/lang/String;IJ)Z`
+    pub fn descriptor(&self) -> String {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.java_signature())
+            .collect::<String>();
+
+        "(".to_owned() + &parameters + ")" + &self.return_type.java_signature()
+    }
+}
+
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.descriptor())
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    with_local_frame, EasyJNIResult, EasyJniError, JavaObject, JavaType, JavaTypeSignature,
+    MethodSignature,
+};
+use jni::{
+    objects::{JClass, JObject, JObjectArray, JString},
+    JNIEnv,
+};
+use std::{collections::BTreeMap, sync::Mutex};
+
+/// `java.lang.reflect.Modifier.STATIC`
+pub(crate) const STATIC_MODIFIER: i32 = 0x0008;
+
+/// The name the JVM gives to every constructor
+const CONSTRUCTOR_NAME: &str = "<init>";
+
+/// Overloads already chosen for a call, `None` when no overload accepts the arguments.
+/// Classes are told apart by name, so classes of the same name loaded by different
+/// class loaders share their entries
+static RESOLVED_OVERLOADS: Mutex<BTreeMap<OverloadKey, Option<MethodSignature>>> =
+    Mutex::new(BTreeMap::new());
+
+/// What the chosen overload depends on, arguments are described by their type
+/// and `None` for `null` which fits any reference type
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct OverloadKey {
+    class_name: String,
+    name: String,
+    is_static: bool,
+    arguments: Vec<Option<JavaTypeSignature>>,
+}
+
+impl MethodSignature {
+    /// Picks the overload of the public method `name` of `class` that Java would call
+    /// with `arguments`. As in javac, overloads reached through widening primitive
+    /// conversions like `int` to `long` win over the ones that need boxing or unboxing,
+    /// then the most specific overload is selected.
+    /// Variable arity methods only accept an array for their last parameter
+    pub fn resolve_method<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: &JClass<'local>,
+        name: &str,
+        is_static: bool,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<MethodSignature> {
+        Self::resolve(env, java_class, class, name, is_static, arguments)
+    }
+
+    /// Picks the public constructor of `class` that Java would call with `arguments`,
+    /// like one of the constructors generated for `@JvmOverloads` default parameters
+    pub fn resolve_constructor<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: &JClass<'local>,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<MethodSignature> {
+        Self::resolve(env, java_class, class, CONSTRUCTOR_NAME, false, arguments)
+    }
+
+    /// Looks the overload up in `RESOLVED_OVERLOADS` before going through reflection
+    fn resolve<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: &JClass<'local>,
+        name: &str,
+        is_static: bool,
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<MethodSignature> {
+        let key = OverloadKey {
+            class_name: JavaObject::class_name_of(env, java_class, class)?,
+            name: name.to_owned(),
+            is_static,
+            arguments: arguments
+                .iter()
+                .map(|argument| (!argument.is_null()).then(|| argument.to_java_type_signature()))
+                .collect(),
+        };
+
+        // A poisoned cache is skipped, resolving again gives the same outcome
+        let cached = RESOLVED_OVERLOADS
+            .lock()
+            .ok()
+            .and_then(|resolved| resolved.get(&key).cloned());
+
+        if let Some(resolved) = cached {
+            return resolved.ok_or_else(|| EasyJniError::NoApplicableMethod {
+                name: name.to_owned(),
+            });
+        }
+
+        let candidates = Self::public_members(env, java_class, class, name, is_static)?;
+        let outcome = Self::most_specific(env, java_class, name, &candidates, arguments);
+
+        let resolved = match &outcome {
+            Ok(signature) => Some(Some(signature.clone())),
+            Err(EasyJniError::NoApplicableMethod { .. }) => Some(None),
+            Err(_) => None,
+        };
+
+        if let (Some(resolved), Ok(mut cache)) = (resolved, RESOLVED_OVERLOADS.lock()) {
+            cache.insert(key, resolved);
+        }
+
+        outcome
+    }
+
+    /// The signatures of the public methods named `name` found through `Class.getMethods`,
+    /// or of the public constructors for `<init>`. Bridge and synthetic members are skipped
+    fn public_members<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: &JClass<'local>,
+        name: &str,
+        is_static: bool,
+    ) -> EasyJNIResult<Vec<MethodSignature>> {
+        with_local_frame(env, java_class, 2, |env, java_class| {
+            let (getter, member_class) = if name == CONSTRUCTOR_NAME {
+                ("getConstructors", "java/lang/reflect/Constructor")
+            } else {
+                ("getMethods", "java/lang/reflect/Method")
+            };
+            let get_members = MethodSignature::new(
+                Vec::new(),
+                JavaTypeSignature::array(JavaTypeSignature::object(member_class)),
+            );
+
+            let members = env
+                .call_method(class, getter, get_members.descriptor(), &[])?
+                .l()?;
+            let members = JObjectArray::from(members);
+
+            let mut signatures: Vec<MethodSignature> = Vec::new();
+
+            for index in 0..env.get_array_length(&members)? {
+                // Every member gets its own frame so classes with many methods
+                // do not run out of local references
+                let signature = with_local_frame(env, java_class, 8, |env, java_class| {
+                    let member = env.get_object_array_element(&members, index)?;
+
+                    Self::member_signature(env, java_class, &member, name, is_static)
+                })?;
+
+                let Some(signature) = signature else {
+                    continue;
+                };
+
+                // A method inherited from an interface can show up next to its override
+                if !signatures
+                    .iter()
+                    .any(|other| other.parameters() == signature.parameters())
+                {
+                    signatures.push(signature);
+                }
+            }
+
+            Ok(signatures)
+        })
+    }
+
+    /// The signature of a `java.lang.reflect.Method` or `Constructor`, `None` when
+    /// the method does not match `name` and `is_static` or when it is synthetic,
+    /// like the constructor Kotlin adds for default parameters which takes
+    /// a bit mask and a `DefaultConstructorMarker`
+    fn member_signature<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        member: &JObject<'local>,
+        name: &str,
+        is_static: bool,
+    ) -> EasyJNIResult<Option<MethodSignature>> {
+        let get_class =
+            MethodSignature::new(Vec::new(), JavaTypeSignature::object("java/lang/Class"));
+        let is_synthetic = MethodSignature::new(Vec::new(), JavaTypeSignature::Boolean);
+
+        if env
+            .call_method(member, "isSynthetic", is_synthetic.descriptor(), &[])?
+            .z()?
+        {
+            return Ok(None);
+        }
+
+        let return_type = if name == CONSTRUCTOR_NAME {
+            JavaTypeSignature::Void
+        } else {
+            let get_name = MethodSignature::new(Vec::new(), JavaTypeSignature::String);
+            let get_modifiers = MethodSignature::new(Vec::new(), JavaTypeSignature::Int);
+            let is_bridge = MethodSignature::new(Vec::new(), JavaTypeSignature::Boolean);
+
+            let member_name = env
+                .call_method(member, "getName", get_name.descriptor(), &[])?
+                .l()?;
+            let member_name = JString::from(member_name);
+            // Safety: `Method.getName` returns a `java.lang.String`
+            let member_name: String = unsafe { env.get_string_unchecked(&member_name)? }.into();
+            let modifiers = env
+                .call_method(member, "getModifiers", get_modifiers.descriptor(), &[])?
+                .i()?;
+            let bridge = env
+                .call_method(member, "isBridge", is_bridge.descriptor(), &[])?
+                .z()?;
+
+            if member_name != name || bridge || (modifiers & STATIC_MODIFIER != 0) != is_static {
+                return Ok(None);
+            }
+
+            let return_type = env
+                .call_method(member, "getReturnType", get_class.descriptor(), &[])?
+                .l()?;
+
+            Self::reflected_type(env, java_class, return_type)?
+        };
+
+        let get_parameter_types = MethodSignature::new(
+            Vec::new(),
+            JavaTypeSignature::array(JavaTypeSignature::object("java/lang/Class")),
+        );
+        let parameter_types = env
+            .call_method(
+                member,
+                "getParameterTypes",
+                get_parameter_types.descriptor(),
+                &[],
+            )?
+            .l()?;
+        let parameter_types = JObjectArray::from(parameter_types);
+
+        let mut parameters = Vec::new();
+
+        for index in 0..env.get_array_length(&parameter_types)? {
+            let parameter_type = env.get_object_array_element(&parameter_types, index)?;
+
+            parameters.push(Self::reflected_type(env, java_class, parameter_type)?);
+        }
+
+        Ok(Some(MethodSignature::new(parameters, return_type)))
+    }
+
+    /// The signature of a `java.lang.Class` returned by reflection,
+    /// the local reference to the class is deleted
+    fn reflected_type<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        class: JObject<'local>,
+    ) -> EasyJNIResult<JavaTypeSignature> {
+        let class = JClass::from(class);
+        let class_name = JavaObject::class_name_of(env, java_class, &class)?;
+        env.delete_local_ref(class)?;
+
+        JavaTypeSignature::from_class_name(&class_name)
+    }
+
+    fn most_specific<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        name: &str,
+        candidates: &[MethodSignature],
+        arguments: &[JavaType],
+    ) -> EasyJNIResult<MethodSignature> {
+        let candidates = candidates
+            .iter()
+            .filter(|candidate| candidate.parameters().len() == arguments.len())
+            .collect::<Vec<_>>();
+
+        // Overloads that need boxing or unboxing are only considered
+        // when no overload accepts the arguments without it
+        for boxing in [false, true] {
+            let mut applicable = Vec::new();
+
+            for candidate in &candidates {
+                if Self::accepts_all(env, java_class, candidate, arguments, boxing)? {
+                    applicable.push(*candidate);
+                }
+            }
+
+            if applicable.is_empty() {
+                continue;
+            }
+
+            let mut maximal = Vec::new();
+
+            'candidates: for candidate in &applicable {
+                for other in &applicable {
+                    if !Self::is_more_specific(env, java_class, candidate, other)? {
+                        continue 'candidates;
+                    }
+                }
+
+                maximal.push(*candidate);
+            }
+
+            return match maximal.as_slice() {
+                [signature] => Ok((*signature).clone()),
+                _ => Err(EasyJniError::AmbiguousMethodCall {
+                    name: name.to_owned(),
+                    candidates: applicable
+                        .iter()
+                        .map(|signature| signature.descriptor())
+                        .collect(),
+                }),
+            };
+        }
+
+        Err(EasyJniError::NoApplicableMethod {
+            name: name.to_owned(),
+        })
+    }
+
+    fn accepts_all<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        candidate: &MethodSignature,
+        arguments: &[JavaType],
+        boxing: bool,
+    ) -> EasyJNIResult<bool> {
+        for (parameter, argument) in candidate.parameters().iter().zip(arguments) {
+            if !Self::accepts(env, java_class, parameter, argument, boxing)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Whether `argument` can be passed for a parameter declared with the type `parameter`.
+    /// Objects are matched by the class they are held as, so a [`JavaObject::cast`]
+    /// picks overloads like the static type does in javac
+    fn accepts<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        parameter: &JavaTypeSignature,
+        argument: &JavaType,
+        boxing: bool,
+    ) -> EasyJNIResult<bool> {
+        let argument_type = argument.to_java_type_signature();
+
+        if parameter.is_primitive() {
+            let argument_type = if boxing {
+                argument_type.unboxed()
+            } else {
+                argument_type
+            };
+
+            return Ok(argument_type.widens_to(parameter));
+        }
+
+        match argument {
+            JavaType::Null => Ok(true),
+            JavaType::Void => Ok(false),
+            _ if argument_type.is_primitive() => {
+                Ok(boxing && Self::is_subtype(env, java_class, &argument_type.boxed(), parameter)?)
+            }
+            _ => Self::is_subtype(env, java_class, &argument_type, parameter),
+        }
+    }
+
+    /// Whether every parameter of `first` can be passed to the matching parameter of `second`
+    fn is_more_specific<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        first: &MethodSignature,
+        second: &MethodSignature,
+    ) -> EasyJNIResult<bool> {
+        for (first, second) in first.parameters().iter().zip(second.parameters()) {
+            let more_specific = match (first.is_primitive(), second.is_primitive()) {
+                (true, true) => first.widens_to(second),
+                (false, false) => Self::is_subtype(env, java_class, first, second)?,
+                _ => false,
+            };
+
+            if !more_specific {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Whether a reference of the type `subtype` can be assigned to `supertype`
+    fn is_subtype<'local>(
+        env: &mut JNIEnv<'local>,
+        java_class: &JClass<'local>,
+        subtype: &JavaTypeSignature,
+        supertype: &JavaTypeSignature,
+    ) -> EasyJNIResult<bool> {
+        if subtype == supertype {
+            return Ok(true);
+        }
+
+        with_local_frame(env, java_class, 2, |env, _| {
+            let subtype = env.find_class(subtype.java_class_name())?;
+            let supertype = env.find_class(supertype.java_class_name())?;
+
+            Ok(env.is_assignable_from(&subtype, &supertype)?)
+        })
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{EasyJNIResult, EasyJniError, JavaTypeSignature, MethodSignature};
+use std::str::FromStr;
+
+/// The JVM limits arrays to 255 dimensions
+const MAX_ARRAY_DIMENSIONS: usize = 255;
+
+/// Reads JNI type and method descriptors one byte at a time
+/// keeping track of the position for error reporting
+struct DescriptorParser<'a> {
+    descriptor: &'a str,
+    position: usize,
+}
+
+impl<'a> DescriptorParser<'a> {
+    fn new(descriptor: &'a str) -> Self {
+        DescriptorParser {
+            descriptor,
+            position: 0,
+        }
+    }
+
+    fn error(&self, position: usize, reason: &'static str) -> EasyJniError {
+        EasyJniError::InvalidDescriptor {
+            descriptor: self.descriptor.to_owned(),
+            position,
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.descriptor.as_bytes().get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+
+        Some(byte)
+    }
+
+    fn expect(&mut self, expected: u8, reason: &'static str) -> EasyJNIResult<()> {
+        match self.next() {
+            Some(byte) if byte == expected => Ok(()),
+            _ => Err(self.error(self.position.saturating_sub(1), reason)),
+        }
+    }
+
+    fn finish(&self) -> EasyJNIResult<()> {
+        if self.position == self.descriptor.len() {
+            Ok(())
+        } else {
+            Err(self.error(self.position, "expected end of descriptor"))
+        }
+    }
+
+    fn return_type(&mut self) -> EasyJNIResult<JavaTypeSignature> {
+        if self.peek() == Some(b'V') {
+            self.position += 1;
+
+            Ok(JavaTypeSignature::Void)
+        } else {
+            self.field_type()
+        }
+    }
+
+    fn field_type(&mut self) -> EasyJNIResult<JavaTypeSignature> {
+        let start = self.position;
+
+        let java_type = match self.next() {
+            Some(b'B') => JavaTypeSignature::Byte,
+            Some(b'S') => JavaTypeSignature::Short,
+            Some(b'I') => JavaTypeSignature::Int,
+            Some(b'J') => JavaTypeSignature::Long,
+            Some(b'F') => JavaTypeSignature::Float,
+            Some(b'D') => JavaTypeSignature::Double,
+            Some(b'Z') => JavaTypeSignature::Boolean,
+            Some(b'C') => JavaTypeSignature::Char,
+            Some(b'L') => self.class_name()?,
+            Some(b'[') => {
+                let mut dimensions = 1;
+
+                while self.peek() == Some(b'[') {
+                    self.position += 1;
+                    dimensions += 1;
+                }
+
+                if dimensions > MAX_ARRAY_DIMENSIONS {
+                    return Err(self.error(start, "array has more than 255 dimensions"));
+                }
+
+                let mut java_type = self.field_type()?;
+
+                for _ in 0..dimensions {
+                    java_type = JavaTypeSignature::array(java_type);
+                }
+
+                java_type
+            }
+            Some(b'V') => return Err(self.error(start, "void is only allowed as a return type")),
+            Some(_) => return Err(self.error(start, "expected a type descriptor")),
+            None => return Err(self.error(start, "unexpected end of descriptor")),
+        };
+
+        Ok(java_type)
+    }
+
+    fn class_name(&mut self) -> EasyJNIResult<JavaTypeSignature> {
+        let start = self.position;
+
+        let length = self.descriptor[start..]
+            .find(';')
+            .ok_or_else(|| self.error(start, "class name is missing the closing `;`"))?;
+        let class_name = &self.descriptor[start..start + length];
+
+        if class_name.is_empty() {
+            return Err(self.error(start, "class name is empty"));
+        }
+
+        if let Some(offset) = class_name.find(['.', '[', '(', ')']) {
+            return Err(self.error(start + offset, "invalid character in class name"));
+        }
+
+        let empty_segment = if class_name.starts_with('/') {
+            Some(0)
+        } else if class_name.ends_with('/') {
+            Some(length - 1)
+        } else {
+            class_name.find("//").map(|offset| offset + 1)
+        };
+
+        if let Some(offset) = empty_segment {
+            return Err(self.error(start + offset, "empty package or class name segment"));
+        }
+
+        self.position = start + length + 1;
+
+        Ok(JavaTypeSignature::object(class_name))
+    }
+
+    fn method(&mut self) -> EasyJNIResult<MethodSignature> {
+        self.expect(b'(', "method descriptor must start with `(`")?;
+
+        let mut parameters = Vec::new();
+
+        loop {
+            match self.peek() {
+                Some(b')') => {
+                    self.position += 1;
+                    break;
+                }
+                None => {
+                    return Err(self.error(self.position, "parameters are missing the closing `)`"))
+                }
+                Some(_) => parameters.push(self.field_type()?),
+            }
+        }
+
+        let return_type = self.return_type()?;
+
+        Ok(MethodSignature::new(parameters, return_type))
+    }
+}
+
+impl JavaTypeSignature {
+    /// Parses a JNI type descriptor like `[[D` or `Ljava/lang/String;`.
+    /// `V` is accepted since `Void` is a valid return type
+    pub fn parse(descriptor: &str) -> EasyJNIResult<Self> {
+        let mut parser = DescriptorParser::new(descriptor);
+
+        let java_type = parser.return_type()?;
+        parser.finish()?;
+
+        Ok(java_type)
+    }
+}
+
+impl FromStr for JavaTypeSignature {
+    type Err = EasyJniError;
+
+    fn from_str(descriptor: &str) -> Result<Self, Self::Err> {
+        JavaTypeSignature::parse(descriptor)
+    }
+}
+
+impl MethodSignature {
+    /// Parses a JNI method descriptor like `([Ljava/lang/String;IJ)Z`
+    pub fn parse(descriptor: &str) -> EasyJNIResult<Self> {
+        let mut parser = DescriptorParser::new(descriptor);
+
+        let method = parser.method()?;
+        parser.finish()?;
+
+        Ok(method)
+    }
+}
+
+impl FromStr for MethodSignature {
+    type Err = EasyJniError;
+
+    fn from_str(descriptor: &str) -> Result<Self, Self::Err> {
+        MethodSignature::parse(descriptor)
+    }
+}
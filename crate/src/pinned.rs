@@ -0,0 +1,162 @@
+use crate::{EasyJNIResult, EasyJniError, JavaPrimitive, JavaTypeSignature};
+use jni::{
+    objects::{AutoElements, AutoElementsCritical, JClass, JPrimitiveArray, ReleaseMode},
+    JNIEnv,
+};
+use std::ops::{Deref, DerefMut};
+
+/// Checks that the Java array really holds elements of type `T`
+/// since `JPrimitiveArray` can be created from any object without a check
+fn check_element_type<'local, T: JavaPrimitive>(
+    env: &mut JNIEnv<'local>,
+    array: &JPrimitiveArray<T>,
+) -> EasyJNIResult<()> {
+    let class_name = JavaTypeSignature::array(T::java_type()).java_class_name();
+
+    if env.is_instance_of(array, class_name)? {
+        Ok(())
+    } else {
+        Err(EasyJniError::JavaTypeMismatch)
+    }
+}
+
+/// Access to the elements of a Java primitive array through `Get<Type>ArrayElements`
+/// without copying them into a `JavaArray`. The JVM may still hand out a copy,
+/// see [`PinnedArray::is_copy`]. Changes are written back on drop when created with
+/// `ReleaseMode::CopyBack`, [`PinnedArray::abort`] drops them instead
+pub struct PinnedArray<'local, 'other_local, 'array, T: JavaPrimitive> {
+    elements: AutoElements<'local, 'other_local, 'array, T>,
+}
+
+impl<'local, 'other_local, 'array, T: JavaPrimitive> PinnedArray<'local, 'other_local, 'array, T> {
+    /// # Safety
+    ///
+    /// No other Rust or Java thread may access the array while it is pinned
+    /// and only one `PinnedArray` or `CriticalArray` may exist per Java array at a time
+    pub unsafe fn new(
+        env: &mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        array: &'array JPrimitiveArray<'other_local, T>,
+        mode: ReleaseMode,
+    ) -> EasyJNIResult<Self> {
+        check_element_type(env, array)?;
+
+        Ok(PinnedArray {
+            elements: env.get_array_elements(array, mode)?,
+        })
+    }
+
+    pub fn java_type(&self) -> JavaTypeSignature {
+        T::java_type()
+    }
+
+    /// Whether the JVM copied the elements instead of pinning them
+    pub fn is_copy(&self) -> bool {
+        self.elements.is_copy()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    /// Writes the changes back to the Java array while keeping it pinned
+    pub fn commit(&mut self) -> EasyJNIResult<()> {
+        Ok(self.elements.commit()?)
+    }
+
+    /// Releases the array with the mode given to [`PinnedArray::new`], changes are written
+    /// back with `ReleaseMode::CopyBack` and dropped with `ReleaseMode::NoCopyBack`
+    /// unless they were written back with [`PinnedArray::commit`]
+    pub fn release(self) {}
+
+    /// Releases the array discarding any change not yet written back with [`PinnedArray::commit`]
+    pub fn abort(mut self) {
+        self.elements.discard();
+    }
+}
+
+impl<T: JavaPrimitive> Deref for PinnedArray<'_, '_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: JavaPrimitive> DerefMut for PinnedArray<'_, '_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+/// Access to the elements of a Java primitive array through `GetPrimitiveArrayCritical`,
+/// which is the most likely to avoid a copy. The garbage collector may be paused
+/// until the guard is dropped and the `JNIEnv` stays borrowed so no JNI call can be made
+pub struct CriticalArray<'local, 'other_local, 'array, 'env, T: JavaPrimitive> {
+    elements: AutoElementsCritical<'local, 'other_local, 'array, 'env, T>,
+}
+
+impl<'local, 'other_local, 'array, 'env, T: JavaPrimitive>
+    CriticalArray<'local, 'other_local, 'array, 'env, T>
+{
+    /// # Safety
+    ///
+    /// Besides the rules of [`PinnedArray::new`], no system call that waits
+    /// on another Java thread may be made while the guard is alive
+    pub unsafe fn new(
+        env: &'env mut JNIEnv<'local>,
+        _: &JClass<'local>,
+        array: &'array JPrimitiveArray<'other_local, T>,
+        mode: ReleaseMode,
+    ) -> EasyJNIResult<Self> {
+        check_element_type(env, array)?;
+
+        Ok(CriticalArray {
+            elements: env.get_array_elements_critical(array, mode)?,
+        })
+    }
+
+    pub fn java_type(&self) -> JavaTypeSignature {
+        T::java_type()
+    }
+
+    /// Whether the JVM copied the elements instead of pinning them
+    pub fn is_copy(&self) -> bool {
+        self.elements.is_copy()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    /// Ends the critical section with the mode given to [`CriticalArray::new`], changes are
+    /// written back with `ReleaseMode::CopyBack` and dropped with `ReleaseMode::NoCopyBack`
+    pub fn release(self) {}
+
+    /// Ends the critical section discarding the changes
+    pub fn abort(mut self) {
+        self.elements.discard();
+    }
+}
+
+impl<T: JavaPrimitive> Deref for CriticalArray<'_, '_, '_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: JavaPrimitive> DerefMut for CriticalArray<'_, '_, '_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{EasyJNIResult, EasyJniError, JavaType, JavaTypeSignature};
+use jni::{
+    errors::Result as JniResult,
+    objects::{JPrimitiveArray, TypeArray},
+    sys::{jboolean, jbyte, jchar, jdouble, jfloat, jint, jlong, jshort, jsize},
+    JNIEnv,
+};
+
+/// The element types of Java primitive arrays like `int[]` and Kotlin `IntArray`.
+/// Booleans are `jboolean` (`u8`) and chars are UTF-16 code units (`u16`) since
+/// that is how the JVM stores them, which allows bulk copies without conversion
+pub trait JavaPrimitive: TypeArray + Default + 'static {
+    fn java_type() -> JavaTypeSignature;
+
+    fn new_array<'local>(
+        env: &JNIEnv<'local>,
+        length: jsize,
+    ) -> JniResult<JPrimitiveArray<'local, Self>>;
+
+    /// Copies `buffer.len()` elements starting at `start` out of the Java array
+    fn get_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &mut [Self],
+    ) -> JniResult<()>;
+
+    /// Copies the whole `buffer` into the Java array starting at `start`
+    fn set_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &[Self],
+    ) -> JniResult<()>;
+
+    fn to_java_type(self) -> EasyJNIResult<JavaType>;
+
+    fn from_java_type(value: &JavaType) -> EasyJNIResult<Self>;
+}
+
+macro_rules! java_primitive {
+    ($element:ty, $signature:ident, $new:ident, $get:ident, $set:ident) => {
+        impl JavaPrimitive for $element {
+            fn java_type() -> JavaTypeSignature {
+                JavaTypeSignature::$signature
+            }
+
+            fn new_array<'local>(
+                env: &JNIEnv<'local>,
+                length: jsize,
+            ) -> JniResult<JPrimitiveArray<'local, Self>> {
+                env.$new(length)
+            }
+
+            fn get_region(
+                env: &JNIEnv,
+                array: &JPrimitiveArray<Self>,
+                start: jsize,
+                buffer: &mut [Self],
+            ) -> JniResult<()> {
+                env.$get(array, start, buffer)
+            }
+
+            fn set_region(
+                env: &JNIEnv,
+                array: &JPrimitiveArray<Self>,
+                start: jsize,
+                buffer: &[Self],
+            ) -> JniResult<()> {
+                env.$set(array, start, buffer)
+            }
+
+            fn to_java_type(self) -> EasyJNIResult<JavaType> {
+                Ok(JavaType::$signature(self))
+            }
+
+            fn from_java_type(value: &JavaType) -> EasyJNIResult<Self> {
+                match value {
+                    JavaType::$signature(inner_value) => Ok(*inner_value),
+                    _ => Err(EasyJniError::JavaTypeMismatch),
+                }
+            }
+        }
+    };
+}
+
+java_primitive!(
+    jbyte,
+    Byte,
+    new_byte_array,
+    get_byte_array_region,
+    set_byte_array_region
+);
+java_primitive!(
+    jshort,
+    Short,
+    new_short_array,
+    get_short_array_region,
+    set_short_array_region
+);
+java_primitive!(
+    jint,
+    Int,
+    new_int_array,
+    get_int_array_region,
+    set_int_array_region
+);
+java_primitive!(
+    jlong,
+    Long,
+    new_long_array,
+    get_long_array_region,
+    set_long_array_region
+);
+java_primitive!(
+    jfloat,
+    Float,
+    new_float_array,
+    get_float_array_region,
+    set_float_array_region
+);
+java_primitive!(
+    jdouble,
+    Double,
+    new_double_array,
+    get_double_array_region,
+    set_double_array_region
+);
+
+impl JavaPrimitive for jboolean {
+    fn java_type() -> JavaTypeSignature {
+        JavaTypeSignature::Boolean
+    }
+
+    fn new_array<'local>(
+        env: &JNIEnv<'local>,
+        length: jsize,
+    ) -> JniResult<JPrimitiveArray<'local, Self>> {
+        env.new_boolean_array(length)
+    }
+
+    fn get_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &mut [Self],
+    ) -> JniResult<()> {
+        env.get_boolean_array_region(array, start, buffer)
+    }
+
+    fn set_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &[Self],
+    ) -> JniResult<()> {
+        env.set_boolean_array_region(array, start, buffer)
+    }
+
+    fn to_java_type(self) -> EasyJNIResult<JavaType> {
+        Ok(JavaType::Boolean(self != 0))
+    }
+
+    fn from_java_type(value: &JavaType) -> EasyJNIResult<Self> {
+        match value {
+            JavaType::Boolean(inner_value) => Ok(*inner_value as jboolean),
+            _ => Err(EasyJniError::JavaTypeMismatch),
+        }
+    }
+}
+
+impl JavaPrimitive for jchar {
+    fn java_type() -> JavaTypeSignature {
+        JavaTypeSignature::Char
+    }
+
+    fn new_array<'local>(
+        env: &JNIEnv<'local>,
+        length: jsize,
+    ) -> JniResult<JPrimitiveArray<'local, Self>> {
+        env.new_char_array(length)
+    }
+
+    fn get_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &mut [Self],
+    ) -> JniResult<()> {
+        env.get_char_array_region(array, start, buffer)
+    }
+
+    fn set_region(
+        env: &JNIEnv,
+        array: &JPrimitiveArray<Self>,
+        start: jsize,
+        buffer: &[Self],
+    ) -> JniResult<()> {
+        env.set_char_array_region(array, start, buffer)
+    }
+
+    fn to_java_type(self) -> EasyJNIResult<JavaType> {
+        Ok(JavaType::Char(JavaType::char_from_utf16(self)?))
+    }
+
+    fn from_java_type(value: &JavaType) -> EasyJNIResult<Self> {
+        match value {
+            JavaType::Char(inner_value) => JavaType::char_to_utf16(*inner_value),
+            _ => Err(EasyJniError::JavaTypeMismatch),
+        }
+    }
+}
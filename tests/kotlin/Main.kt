@@ -1,3 +1,5 @@
+import java.nio.ByteBuffer
+
 fun main() {
     System.loadLibrary("rust_kotlin")
 
@@ -41,6 +43,127 @@ fun main() {
 
     }
 
+    val myChars = rustyArrayChars()
+
+    if (!myChars.contentEquals(arrayOf('R', 'ü', 'λ'))) {
+        throw RuntimeException("\nCHAR MISMATCH\nLEFT: ${myChars.joinToString()}")
+    }
+
+    val rustyObject = RustyClass()
+    val myObjects = rustyObjects(rustyObject)
+
+    if (myObjects.size != 2 || myObjects.any { it !== rustyObject }) {
+        throw RuntimeException("`rustyObjects` is supposed to return the same object twice")
+    }
+
+    val myNullables = rustyNullables()
+
+    if (!myNullables.contentEquals(arrayOf("ONE", null))) {
+        throw RuntimeException("\nNULLABLE MISMATCH\nLEFT: ${myNullables.joinToString()}")
+    }
+
+    val myNestedArrays = rustyNestedArrays()
+    val testNestedArrays = arrayOf(arrayOf("ONE", "TWO"), arrayOf("THREE", "FOUR"))
+
+    if (!myNestedArrays.contentDeepEquals(testNestedArrays)) {
+        throw RuntimeException("\nNESTED ARRAY MISMATCH\nLEFT: ${myNestedArrays.contentDeepToString()}")
+    }
+
+    val myMixedArray = rustyMixedArray()
+    val testMixedArray = arrayOf<Any?>("ONE", 2, null, arrayOf("INNER"))
+
+    if (!myMixedArray.contentDeepEquals(testMixedArray)) {
+        throw RuntimeException("\nMIXED ARRAY MISMATCH\nLEFT: ${myMixedArray.contentDeepToString()}")
+    }
+
+    val myIntArray = rustyIntArray(intArrayOf(1, 2, 3))
+
+    if (!myIntArray.contentEquals(intArrayOf(2, 4, 6))) {
+        throw RuntimeException("\nINT ARRAY MISMATCH\nLEFT: ${myIntArray.joinToString()}")
+    }
+
+    val myDoubleArray = rustyDoubleArray()
+
+    if (!myDoubleArray.contentEquals(doubleArrayOf(0.5, 1.5, 2.5))) {
+        throw RuntimeException("\nDOUBLE ARRAY MISMATCH\nLEFT: ${myDoubleArray.joinToString()}")
+    }
+
+    val sensorBuffer = floatArrayOf(1.0f, 2.0f, 3.0f)
+    val sensorSum = rustySensorBuffer(sensorBuffer)
+
+    if (!sensorBuffer.contentEquals(floatArrayOf(2.0f, 4.0f, 6.0f)) || sensorSum != 12.0f) {
+        throw RuntimeException("\nSENSOR BUFFER MISMATCH\nLEFT: ${sensorBuffer.joinToString()}, SUM: $sensorSum")
+    }
+
+    val wordCount = rustyWordCount(arrayOf("read me", null, "from kotlin"))
+
+    if (wordCount != 4) {
+        throw RuntimeException("\nWORD COUNT MISMATCH\nLEFT: $wordCount")
+    }
+
+    val joined = rustyJoinStrings(arrayOf("JOINED", "IN", "RUST"))
+
+    if (joined != "JOINED IN RUST") {
+        throw RuntimeException("\nJOINED STRINGS MISMATCH\nLEFT: $joined")
+    }
+
+    val point = rustyPoint()
+
+    if (point.x != 3 || point.label != "ORIGIN" || point.z != null || !point.initialized || !point.tags.contentEquals(arrayOf("DEFAULT"))) {
+        throw RuntimeException("\nRUSTY POINT MISMATCH\nLEFT: ${point.x}, ${point.label}, ${point.z}, ${point.initialized}")
+    }
+
+    val profile = RustyProfile("FERRIS", 8, intArrayOf(1, 2, 3), null, RustyPoint(1, "HOME", null))
+    val profileSummary = rustyProfileSummary(profile)
+
+    if (profileSummary != "FERRIS:8:6:None:42:HOME") {
+        throw RuntimeException("\nPROFILE SUMMARY MISMATCH\nLEFT: $profileSummary")
+    }
+
+    val configSum = rustyConfig()
+
+    if (configSum != RustyConfig.LIMIT + RUSTY_VERSION || RustyConfig.greeting != "HELLO FROM RUST") {
+        throw RuntimeException("\nRUSTY CONFIG MISMATCH\nLEFT: $configSum, ${RustyConfig.greeting}")
+    }
+
+    val callPoint = RustyPoint(0, "AB", null)
+    val calls = rustyCalls(callPoint)
+
+    if (calls != ">ABAB:42" || callPoint.tags[0] != "TOUCHED") {
+        throw RuntimeException("\nRUSTY CALLS MISMATCH\nLEFT: $calls, ${callPoint.tags[0]}")
+    }
+
+    val overloads = rustyOverloads()
+
+    if (overloads != "LONG,DOUBLE,STRING,ANY,ANY:INT_LONG:TRIANGLE:2:SHAPE:1:130:15") {
+        throw RuntimeException("\nRUSTY OVERLOADS MISMATCH\nLEFT: $overloads")
+    }
+
+    val superCalls = rustySuperCalls(RustyDog())
+
+    if (superCalls != "WOOFWOOF:HMMHMM:4:0:RustyAnimal") {
+        throw RuntimeException("\nRUSTY SUPER CALLS MISMATCH\nLEFT: $superCalls")
+    }
+
+    val singletons = rustySingletons()
+
+    if (singletons != "3:9:9:true:8:0:8:8:COUNTER:ABC-MADE" || RustyRegistry.count != 0) {
+        throw RuntimeException("\nRUSTY SINGLETONS MISMATCH\nLEFT: $singletons")
+    }
+
+    val rustBuffer = rustyByteBuffer()
+
+    if (!rustBuffer.isDirect() || rustBuffer.capacity() != 4 || rustBuffer.get(3) != 4.toByte()) {
+        throw RuntimeException("\nRUST BYTE BUFFER MISMATCH\nLEFT: $rustBuffer")
+    }
+
+    val kotlinBuffer = ByteBuffer.allocateDirect(3).put(byteArrayOf(5, 6, 7))
+    val kotlinBufferSum = rustyByteBufferSum(kotlinBuffer)
+
+    if (kotlinBufferSum != 18) {
+        throw RuntimeException("\nKOTLIN BYTE BUFFER MISMATCH\nLEFT: $kotlinBufferSum")
+    }
+
     sillyDebugger()
 
     val myresult  = resultOfArrayString()
@@ -1,13 +1,41 @@
 /** This file is used as a namespace for all the exported Rust functions. */
 @file:JvmName("RustLibrary")
 
+import java.nio.ByteBuffer
+
+const val RUSTY_VERSION = 3
+
 external fun nativeAssertions()
 external fun rustyClass(): RustyClass
 
 external fun rustyArray(): Array<String>
 external fun rustyArrayInts(): Array<Int>
+external fun rustyArrayChars(): Array<Char>
+external fun rustyObjects(rustyObject: RustyClass): Array<RustyClass>
+external fun rustyNullables(): Array<String?>
+external fun rustyNestedArrays(): Array<Array<String>>
+external fun rustyMixedArray(): Array<Any?>
+external fun rustyIntArray(input: IntArray): IntArray
+external fun rustyDoubleArray(): DoubleArray
+external fun rustySensorBuffer(buffer: FloatArray): Float
+external fun rustyWordCount(words: Array<String?>): Int
+external fun rustyJoinStrings(strings: Array<String>): String
+external fun rustyByteBuffer(): ByteBuffer
+external fun rustyByteBufferSum(buffer: ByteBuffer): Int
+external fun rustyPoint(): RustyPoint
+external fun rustyProfileSummary(profile: RustyProfile): String
+external fun rustyConfig(): Int
+external fun rustyCalls(point: RustyPoint): String
+
+external fun rustyOverloads(): String
+
+external fun rustySuperCalls(animal: Any): String
+
+external fun rustySingletons(): String
 external fun resultOfArrayString(): ResultOfArrayString
 
+fun rustyAdd(a: Int, b: Long): Long = a + b
+
 external fun sillyDebugger(): String
 
 
@@ -20,6 +48,128 @@ class RustyClass {
 }
 
 
+class RustyPoint(val x: Int, val label: String, val z: Int?) {
+    val tags: Array<String> = arrayOf("DEFAULT")
+    var initialized = false
+
+    init {
+        initialized = true
+    }
+
+    fun describe(prefix: String, times: Int): String = prefix + label.repeat(times)
+
+    fun touch() {
+        tags[0] = "TOUCHED"
+    }
+}
+
+
+open class RustyEntity {
+    val id: Long = 42
+}
+
+
+class RustyProfile(
+    val name: String,
+    val age: Int,
+    val scores: IntArray,
+    val nickname: String?,
+    val point: RustyPoint
+) : RustyEntity()
+
+
+class RustyConfig {
+    companion object {
+        const val LIMIT = 10
+
+        @JvmField
+        var greeting = "HELLO"
+    }
+}
+
+
+class RustyShape @JvmOverloads constructor(
+    val sides: Int,
+    val name: String = "SHAPE",
+    val size: Double = 1.0
+) {
+    fun pick(value: Long): String = "LONG"
+    fun pick(value: Double): String = "DOUBLE"
+    fun pick(value: String?): String = "STRING"
+    fun pick(value: Any?): String = "ANY"
+
+    fun mix(a: Int, b: Long): String = "INT_LONG"
+    fun mix(a: Long, b: Int): String = "LONG_INT"
+
+    companion object {
+        @JvmStatic
+        fun scale(factor: Int): Int = factor * 2
+
+        @JvmStatic
+        fun scale(factor: Long): Long = factor * 3
+    }
+}
+
+
+open class RustyAnimal {
+    open fun speak(times: Int): String = "HMM".repeat(times)
+
+    open fun legs(): Int = 0
+}
+
+
+class RustyDog : RustyAnimal() {
+    override fun speak(times: Int): String = "WOOF".repeat(times)
+
+    override fun legs(): Int = 4
+}
+
+
+object RustyRegistry {
+    const val CAPACITY = 8
+
+    var count: Int = 0
+
+    val isOpen: Boolean = true
+
+    fun register(name: String): Int {
+        count += name.length
+
+        return count
+    }
+
+    @JvmStatic
+    fun reset(): Int {
+        count = 0
+
+        return count
+    }
+}
+
+
+class RustyCounter {
+    companion object {
+        var total: Long = 5
+
+        fun next(step: Int): Long {
+            total += step
+
+            return total
+        }
+
+        @JvmStatic
+        fun label(): String = "COUNTER"
+    }
+}
+
+
+class RustyFactory {
+    companion object Maker {
+        fun make(prefix: String): String = "$prefix-MADE"
+    }
+}
+
+
 class ResultOfArrayString {
     val successData: Array<String> = emptyArray()
     val failureData: Array<String> = emptyArray()
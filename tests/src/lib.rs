@@ -1,11 +1,15 @@
 use easy_jni::{
     jni::{
-        objects::{JClass, JObject, JString, JValue, JValueOwned},
+        objects::{
+            JByteBuffer, JClass, JFloatArray, JIntArray, JObject, JObjectArray, JString, JValue,
+            JValueOwned, ReleaseMode,
+        },
         strings::JNIString,
-        sys::{jarray, jint, jobject, jstring},
+        sys::{jarray, jfloat, jint, jintArray, jobject, jstring},
         JNIEnv,
     },
-    to_rust, Class, JavaArray, JavaType, JavaTypeSignature,
+    to_rust, Class, CriticalArray, DirectByteBuffer, JavaArray, JavaObject, JavaType,
+    JavaTypeSignature, LocalFrame, MethodSignature, PinnedArray,
 };
 
 mod files;
@@ -14,14 +18,16 @@ pub use files::*;
 #[no_mangle]
 pub extern "system" fn Java_RustLibrary_nativeAssertions<'local>(
     mut env: JNIEnv<'local>,
-    java_class: &JClass<'local>,
+    java_class: JClass<'local>,
 ) {
+    let java_class = &java_class;
+
     {
         let byte_data = 4i8;
 
         let outcome = to_rust(&mut env, java_class, JValue::from(byte_data));
 
-        assert_eq!(Ok(JavaType::Byte(byte_data as u8)), outcome);
+        assert_eq!(Ok(JavaType::Byte(byte_data)), outcome);
     }
 
     {
@@ -29,10 +35,8 @@ pub extern "system" fn Java_RustLibrary_nativeAssertions<'local>(
 
         let outcome = to_rust(&mut env, java_class, JValue::from(byte_data));
 
-        assert_eq!(
-            Err(easy_jni::EasyJniError::ByteLessThanZeroNotSupported),
-            outcome
-        );
+        assert_eq!(Ok(JavaType::Byte(byte_data)), outcome);
+        assert_eq!(Some(252u8), outcome.unwrap().as_unsigned_byte());
     }
 
     {
@@ -62,7 +66,7 @@ pub extern "system" fn Java_RustLibrary_nativeAssertions<'local>(
     {
         let float_data = 504.99f32;
 
-        let outcome = to_rust(&mut env, &java_class, JValue::from(float_data));
+        let outcome = to_rust(&mut env, java_class, JValue::from(float_data));
 
         assert_eq!(Ok(JavaType::Float(float_data)), outcome);
     }
@@ -101,6 +105,362 @@ pub extern "system" fn Java_RustLibrary_nativeAssertions<'local>(
 
         assert_eq!(Ok(JavaType::String(string_data)), outcome);
     }
+
+    {
+        let char_data = 'R';
+
+        let outcome = to_rust(&mut env, java_class, JValue::Char(char_data as u16));
+
+        assert_eq!(Ok(JavaType::Char(char_data)), outcome);
+    }
+
+    {
+        let surrogate_data = 0xD83Du16;
+
+        let outcome = to_rust(&mut env, java_class, JValue::Char(surrogate_data));
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::LoneSurrogate(surrogate_data)),
+            outcome
+        );
+    }
+
+    {
+        let char_data = JavaType::Char('🦀');
+
+        let outcome = char_data.to_jni_jvalue(&mut env, java_class);
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::CharOutsideBmp('🦀')),
+            outcome.map(|_| ())
+        );
+    }
+
+    {
+        let string_array = JavaTypeSignature::array(JavaTypeSignature::String);
+
+        assert_eq!("I", JavaTypeSignature::Int.bytecode_signature());
+        assert_eq!(
+            "Ljava/lang/String;",
+            JavaTypeSignature::String.java_signature()
+        );
+        assert_eq!(
+            "Ljava/util/List;",
+            JavaTypeSignature::object("java.util.List").java_signature()
+        );
+        assert_eq!(
+            "LRustyClass;",
+            JavaTypeSignature::object("RustyClass").java_signature()
+        );
+        assert_eq!(
+            "[[Ljava/lang/String;",
+            JavaTypeSignature::array(string_array.clone()).java_signature()
+        );
+        assert_eq!(
+            Ok("[[Ljava/lang/String;".to_owned()),
+            string_array.java_class_name_array()
+        );
+        assert_eq!(
+            Ok("[Ljava/lang/Integer;".to_owned()),
+            JavaTypeSignature::Int.java_class_name_array()
+        );
+    }
+
+    {
+        let method = MethodSignature::new(
+            vec![
+                JavaTypeSignature::array(JavaTypeSignature::String),
+                JavaTypeSignature::Int,
+                JavaTypeSignature::Long,
+            ],
+            JavaTypeSignature::Boolean,
+        );
+
+        assert_eq!("([Ljava/lang/String;IJ)Z", method.descriptor());
+        assert_eq!(
+            "(Ljava/lang/String;C)V",
+            MethodSignature::from_arguments(
+                &[JavaType::String("RUSTY".to_owned()), JavaType::Char('R')],
+                JavaTypeSignature::Void
+            )
+            .to_string()
+        );
+    }
+
+    {
+        let descriptor = "([Ljava/lang/String;IJ)Z";
+
+        let method = MethodSignature::parse(descriptor).unwrap();
+
+        assert_eq!(descriptor, method.descriptor());
+        assert_eq!(
+            Ok(JavaTypeSignature::array(JavaTypeSignature::array(
+                JavaTypeSignature::Double
+            ))),
+            "[[D".parse::<JavaTypeSignature>()
+        );
+        assert_eq!(
+            Err(easy_jni::EasyJniError::InvalidDescriptor {
+                descriptor: "(ILjava/lang/String)V".to_owned(),
+                position: 3,
+                reason: "class name is missing the closing `;`",
+            }),
+            MethodSignature::parse("(ILjava/lang/String)V")
+        );
+    }
+
+    {
+        let outcome = to_rust(&mut env, java_class, JValue::Object(&JObject::null()));
+
+        assert_eq!(Ok(JavaType::Null), outcome);
+        assert_eq!(JavaType::Null, JavaType::from(None::<String>));
+        assert_eq!(JavaType::Int(4), JavaType::from(Some(4)));
+        assert_eq!(Ok(None), Option::<String>::try_from(JavaType::Null));
+        assert_eq!(Ok(Some(4)), Option::<i32>::try_from(JavaType::Int(4)));
+    }
+
+    {
+        let int_data = JavaType::Int(50);
+
+        let boxed = int_data.to_jni_object(&mut env, java_class).unwrap();
+        let outcome = to_rust(&mut env, java_class, JValue::from(&boxed));
+
+        assert_eq!(Ok(int_data), outcome);
+    }
+
+    {
+        let double_data = JavaType::Double(504.99);
+
+        let boxed = double_data.to_jni_object(&mut env, java_class).unwrap();
+        let outcome = to_rust(&mut env, java_class, JValue::from(&boxed));
+
+        assert_eq!(Ok(double_data), outcome);
+    }
+
+    {
+        let mut ints = JavaArray::new_primitive(JavaTypeSignature::Int)
+            .unwrap()
+            .resize(2);
+        ints = ints.add_value(JavaType::Int(7)).unwrap();
+        ints = ints.add_value(JavaType::Int(-7)).unwrap();
+
+        let mut nested = JavaArray::new(JavaTypeSignature::array(JavaTypeSignature::Int)).resize(2);
+        nested = nested.add_value(JavaType::Array(ints)).unwrap();
+        nested = nested.add_value(JavaType::Null).unwrap();
+
+        let array = nested.to_jni_object(&mut env, java_class).unwrap();
+        let outcome = to_rust(&mut env, java_class, JValue::from(&array));
+
+        assert_eq!(Ok(JavaType::Array(nested)), outcome);
+        assert_eq!(
+            Err(easy_jni::EasyJniError::JavaTypeMismatch),
+            JavaArray::from_java(&mut env, java_class, &array, JavaTypeSignature::String)
+        );
+    }
+
+    {
+        let mut strings = JavaArray::new(JavaTypeSignature::String).resize(2);
+        strings.extend(["ONE", "TWO", "THREE"].map(JavaType::from));
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::ArrayIndexOutOfBounds { index: 2, size: 2 }),
+            strings.build(&mut env, java_class)
+        );
+
+        let strings = strings.resize(5);
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::MissingArrayValue { index: 3, size: 5 }),
+            strings.build(&mut env, java_class)
+        );
+
+        let mut strings = strings.fill_with(JavaType::from("FILL")).unwrap();
+        strings.extend([JavaType::Int(4)]);
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::ArrayElementMismatch { index: 3 }),
+            strings.build(&mut env, java_class)
+        );
+
+        let mixed = [JavaType::Null, JavaType::Int(1), JavaType::from("TWO")]
+            .into_iter()
+            .collect::<JavaArray>();
+
+        assert_eq!(
+            JavaTypeSignature::object("java/lang/Object"),
+            *mixed.java_type()
+        );
+        assert_eq!(Some(&JavaType::Int(1)), mixed.iter().nth(1));
+        assert_eq!(None, mixed.get(3));
+    }
+
+    {
+        let dog = Class::new("RustyDog").create(&mut env, java_class).unwrap();
+        let dog = JavaObject::new(&mut env, java_class, &dog).unwrap();
+
+        let animals = JavaArray::new(JavaTypeSignature::object("RustyAnimal"))
+            .add_value(JavaType::Object(dog.clone()))
+            .unwrap();
+        let array = animals.to_jni_object(&mut env, java_class).unwrap();
+        let animals = JavaArray::from_java(
+            &mut env,
+            java_class,
+            &array,
+            JavaTypeSignature::object("RustyAnimal"),
+        )
+        .unwrap();
+
+        assert!(animals.build(&mut env, java_class).is_ok());
+
+        let sequences = JavaArray::new(JavaTypeSignature::object("java/lang/CharSequence"))
+            .add_value(JavaType::from("ONE"))
+            .unwrap()
+            .add_value(JavaType::Null)
+            .unwrap();
+        let numbers = JavaArray::new(JavaTypeSignature::object("java/lang/Number"))
+            .add_value(JavaType::Int(1))
+            .unwrap();
+
+        assert!(sequences.build(&mut env, java_class).is_ok());
+        assert!(numbers.build(&mut env, java_class).is_ok());
+
+        let points = JavaArray::new(JavaTypeSignature::object("RustyPoint"))
+            .add_value(JavaType::Null)
+            .unwrap()
+            .add_value(JavaType::Object(dog))
+            .unwrap();
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::ArrayElementMismatch { index: 1 }),
+            points.build(&mut env, java_class)
+        );
+    }
+
+    {
+        let mut ints = JavaArray::from(vec![1i32, 2]).into_primitive().unwrap();
+        ints.push(3);
+
+        let array = ints.to_jni_object(&mut env, java_class).unwrap();
+        let outcome = JavaArray::from_java(&mut env, java_class, &array, JavaTypeSignature::Int)
+            .and_then(JavaArray::<i32>::try_from)
+            .unwrap();
+
+        assert_eq!(vec![1, 2, 3], outcome.into_iter().collect::<Vec<i32>>());
+        assert_eq!(
+            Err(easy_jni::EasyJniError::PrimitiveTypeRequired),
+            JavaArray::from(vec![String::from("ONE")]).into_primitive()
+        );
+
+        let names = JavaArray::from(vec![Some(String::from("ONE")), None]);
+        let dynamic = JavaArray::<JavaType>::from(names.clone());
+
+        assert_eq!(JavaType::Null, dynamic[1]);
+        assert_eq!(
+            Ok(names),
+            JavaArray::<Option<String>>::try_from(dynamic.clone())
+        );
+        assert_eq!(
+            Err(easy_jni::EasyJniError::ArrayElementMismatch { index: 1 }),
+            JavaArray::<String>::try_from(dynamic)
+        );
+
+        let mut strings = JavaArray::<String>::default();
+        strings.push(String::from("ONE"));
+
+        assert_eq!(JavaTypeSignature::String, *strings.java_type());
+        assert!(strings.build(&mut env, java_class).is_ok());
+        assert_eq!(
+            Err(easy_jni::EasyJniError::JavaTypeMismatch),
+            JavaArray::<String>::try_from(JavaArray::new(JavaTypeSignature::Int))
+        );
+
+        let flags = JavaArray::from(vec![true, false])
+            .into_primitive()
+            .unwrap()
+            .resize(3)
+            .fill_with(true);
+        let array = flags.to_jni_object(&mut env, java_class).unwrap();
+        let outcome =
+            JavaArray::from_java(&mut env, java_class, &array, JavaTypeSignature::Boolean)
+                .and_then(JavaArray::<bool>::try_from)
+                .unwrap();
+
+        assert_eq!(
+            vec![true, false, true],
+            outcome.into_iter().collect::<Vec<_>>()
+        );
+
+        let letters = JavaArray::from(vec![Some('R'), None])
+            .into_primitive()
+            .unwrap();
+
+        assert_eq!(
+            Err(easy_jni::EasyJniError::ArrayElementMismatch { index: 1 }),
+            letters.build(&mut env, java_class)
+        );
+    }
+
+    {
+        let strings = (0..200_000)
+            .map(|index| format!("STRING {index}"))
+            .collect::<Vec<String>>();
+
+        let array = JavaArray::from_strings(&mut env, java_class, &strings).unwrap();
+        let outcome = JavaArray::to_strings(&mut env, java_class, &array);
+
+        assert_eq!(Ok(strings.clone()), outcome);
+
+        let array = JavaArray::from(strings.clone())
+            .to_jni_object(&mut env, java_class)
+            .unwrap();
+        let outcome = JavaArray::to_strings(&mut env, java_class, &JObjectArray::from(array));
+
+        assert_eq!(Ok(strings), outcome);
+    }
+
+    {
+        let constructed = Class::new("RustyClass")
+            .build(&mut env, java_class)
+            .unwrap();
+        let allocated = Class::new("RustyClass")
+            .skip_constructor()
+            .build(&mut env, java_class)
+            .unwrap();
+
+        let field_signature = JavaTypeSignature::String.java_signature();
+        let message = env
+            .get_field(&constructed, "message", &field_signature)
+            .unwrap()
+            .l()
+            .unwrap();
+
+        assert_eq!(
+            Ok(JavaType::from("FROM_KOTLIN")),
+            to_rust(&mut env, java_class, JValue::from(&message))
+        );
+        assert!(env
+            .get_field(&allocated, "message", &field_signature)
+            .unwrap()
+            .l()
+            .unwrap()
+            .is_null());
+    }
+
+    {
+        // Safety: only `survivor` is used after the frames, through the result of `pop`
+        let mut frame = unsafe { LocalFrame::new(&mut env, java_class, 2) }.unwrap();
+        let survivor = frame.new_string("SURVIVOR").unwrap();
+
+        for _ in 0..100_000 {
+            let inner_frame = unsafe { LocalFrame::new(&mut frame, java_class, 1) }.unwrap();
+            inner_frame.new_string("RELEASED").unwrap();
+        }
+
+        let survivor = JString::from(frame.pop(&survivor).unwrap());
+        let outcome: String = env.get_string(&survivor).unwrap().into();
+
+        assert_eq!("SURVIVOR", outcome);
+    }
 }
 
 #[allow(non_snake_case)]
@@ -136,90 +496,775 @@ pub extern "system" fn Java_RustLibrary_rustyClass<'local>(
 #[no_mangle]
 pub extern "system" fn Java_RustLibrary_rustyArray<'local>(
     mut env: JNIEnv<'local>,
-    java_class: &'local JClass<'local>,
+    java_class: JClass<'local>,
 ) -> jarray {
-    let create_array =
-        JavaArray::create(&mut env, &java_class, JavaTypeSignature::String, 3).unwrap();
+    JavaArray::from_strings(&mut env, &java_class, &["ONE", "TWO", "THREE"])
+        .unwrap()
+        .into_raw()
+}
 
-    let strings = ["ONE", "TWO", "THREE"];
-    for (i, value) in strings.iter().enumerate() {
-        let jstring = env.new_string(value).unwrap();
-        env.set_object_array_element(&create_array, i as jint, &jstring)
-            .unwrap();
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyArrayInts<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    let ints = [0i32, 1, 2]
+        .into_iter()
+        .map(JavaType::Int)
+        .collect::<JavaArray>();
+
+    assert_eq!(JavaTypeSignature::Int, *ints.java_type());
+    assert_eq!(3, ints.len());
+    assert_eq!(JavaType::Int(2), ints[2]);
+
+    ints.build(&mut env, &java_class).unwrap()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyArrayChars<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    let mut chars = JavaArray::new(JavaTypeSignature::Char).resize(3);
+
+    for value in ['R', 'ü', 'λ'] {
+        chars = chars.add_value(JavaType::Char(value)).unwrap();
     }
 
-    create_array.as_raw()
+    chars.build(&mut env, &java_class).unwrap()
 }
 
+#[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_RustLibrary_rustyArrayInts<'local>(
+pub extern "system" fn Java_RustLibrary_rustyObjects<'local>(
     mut env: JNIEnv<'local>,
-    java_class: &'local JClass<'local>,
+    java_class: JClass<'local>,
+    rusty_object: JObject<'local>,
 ) -> jarray {
-    let create_array = JavaArray::create(&mut env, &java_class, JavaTypeSignature::Int, 3).unwrap();
+    let class_signature = JavaTypeSignature::object("RustyClass");
 
-    let strings = [0i32, 1, 2];
-    for (i, value) in strings.iter().enumerate() {
-        let integer_class = env
-            .find_class("java/lang/Integer")
-            .expect("Failed to find java/lang/Integer");
+    let value = to_rust(&mut env, &java_class, JValue::from(&rusty_object)).unwrap();
 
-        let java_value = env
-            .new_object(integer_class, "(I)V", &[JValue::Int(*value)])
-            .unwrap();
-        env.set_object_array_element(&create_array, i as jint, JObject::from(java_value))
-            .unwrap();
+    assert_eq!(class_signature, value.to_java_type_signature());
+
+    JavaArray::new(class_signature)
+        .resize(2)
+        .add_value(value.clone())
+        .unwrap()
+        .add_value(value)
+        .unwrap()
+        .build(&mut env, &java_class)
+        .unwrap()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyNullables<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    JavaArray::new(JavaTypeSignature::String)
+        .resize(2)
+        .fill_with(JavaType::Null)
+        .unwrap()
+        .add_value(JavaType::from(Some("ONE")))
+        .unwrap()
+        .build(&mut env, &java_class)
+        .unwrap()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyNestedArrays<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    let nested = [["ONE", "TWO"], ["THREE", "FOUR"]]
+        .into_iter()
+        .map(|strings| strings.map(String::from).into_iter().collect())
+        .collect::<JavaArray<JavaArray<String>>>();
+
+    assert_eq!(
+        JavaTypeSignature::array(JavaTypeSignature::array(JavaTypeSignature::String)),
+        nested.signature()
+    );
+    assert_eq!("FOUR", nested[1][1]);
+
+    nested.build(&mut env, &java_class).unwrap()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyMixedArray<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    let inner = JavaArray::new(JavaTypeSignature::String)
+        .resize(1)
+        .add_value(JavaType::from("INNER"))
+        .unwrap();
+
+    JavaArray::new(JavaTypeSignature::object("java/lang/Object"))
+        .resize(4)
+        .add_value(JavaType::from("ONE"))
+        .unwrap()
+        .add_value(JavaType::Int(2))
+        .unwrap()
+        .add_value(JavaType::Null)
+        .unwrap()
+        .add_value(JavaType::Array(inner))
+        .unwrap()
+        .build(&mut env, &java_class)
+        .unwrap()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyIntArray<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    input: JIntArray<'local>,
+) -> jintArray {
+    let values = JavaArray::to_vec(&mut env, &java_class, &input).unwrap();
+
+    let doubled = values.iter().map(|value| value * 2).collect::<Vec<i32>>();
+
+    JavaArray::from_slice(&mut env, &java_class, &doubled)
+        .unwrap()
+        .into_raw()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyDoubleArray<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jarray {
+    let mut doubles = JavaArray::new_primitive(JavaTypeSignature::Double)
+        .unwrap()
+        .resize(3);
+
+    for value in [0.5f64, 1.5, 2.5] {
+        doubles = doubles.add_value(JavaType::Double(value)).unwrap();
     }
 
-    create_array.as_raw()
+    assert_eq!(
+        Err(easy_jni::EasyJniError::JavaTypeMismatch),
+        doubles.clone().add_value(JavaType::Null)
+    );
+
+    doubles.build(&mut env, &java_class).unwrap()
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern "system" fn Java_RustLibrary_resultOfArrayString<'local>(
+pub extern "system" fn Java_RustLibrary_rustySensorBuffer<'local>(
     mut env: JNIEnv<'local>,
     java_class: JClass<'local>,
-) -> jobject {
-    let class_name = "ResultOfArrayString";
-    let success_field_name = "successData";
-    let failure_field_name = "failureData";
+    buffer: JFloatArray<'local>,
+) -> jfloat {
+    {
+        let mut pinned =
+            unsafe { PinnedArray::new(&mut env, &java_class, &buffer, ReleaseMode::CopyBack) }
+                .unwrap();
 
-    let success_array =
-        JavaArray::create(&mut env, &java_class, JavaTypeSignature::String, 3).unwrap();
+        pinned.iter_mut().for_each(|value| *value *= 2.0);
+        pinned.release();
+    }
 
     {
-        let strings = ["ONE", "TWO", "THREE"];
-        for (i, value) in strings.iter().enumerate() {
-            let jstring = env.new_string(value).unwrap();
-            env.set_object_array_element(&success_array, i as jint, &jstring)
+        let mut pinned =
+            unsafe { PinnedArray::new(&mut env, &java_class, &buffer, ReleaseMode::CopyBack) }
                 .unwrap();
+
+        // Aborting only discards changes when the JVM handed out a copy
+        if pinned.is_copy() {
+            pinned.iter_mut().for_each(|value| *value = 0.0);
         }
+
+        pinned.abort();
     }
-    let failure_array =
-        JavaArray::create(&mut env, &java_class, JavaTypeSignature::String, 0).unwrap();
 
-    let object = Class::new(class_name)
-        .create(&mut env, &java_class)
+    let critical =
+        unsafe { CriticalArray::new(&mut env, &java_class, &buffer, ReleaseMode::NoCopyBack) }
+            .unwrap();
+
+    critical.iter().sum()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyWordCount<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    words: JObjectArray<'local>,
+) -> jint {
+    let words =
+        JavaArray::from_java(&mut env, &java_class, &words, JavaTypeSignature::String).unwrap();
+
+    assert_eq!(JavaTypeSignature::String, *words.java_type());
+    assert_eq!(JavaType::Null, words.values()[1]);
+
+    words
+        .values()
+        .iter()
+        .filter_map(|word| Option::<String>::try_from(word.clone()).unwrap())
+        .map(|word| word.split_whitespace().count() as jint)
+        .sum()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyJoinStrings<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    strings: JObjectArray<'local>,
+) -> jstring {
+    let strings = JavaArray::to_strings(&mut env, &java_class, &strings).unwrap();
+
+    env.new_string(strings.join(" ")).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyByteBuffer<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jobject {
+    let buffer = DirectByteBuffer::new(vec![1u8, 2, 3, 4]);
+
+    assert!(buffer.is_rust_owned());
+    assert_eq!(4, buffer.len());
+
+    let mut read_only = DirectByteBuffer::new_read_only(b"READ ONLY".as_slice());
+    let object = read_only.to_jni_object(&mut env, &java_class).unwrap();
+    let outcome = to_rust(&mut env, &java_class, JValue::from(&object)).unwrap();
+    let mut from_java = DirectByteBuffer::try_from(outcome).unwrap();
+
+    assert!(from_java.is_read_only());
+    assert_eq!(read_only, from_java);
+    assert_eq!(b"READ ONLY", unsafe { from_java.as_slice() });
+    assert_eq!(
+        Err(easy_jni::EasyJniError::ReadOnlyByteBuffer),
+        unsafe { from_java.as_mut_slice() }.map(|_| ())
+    );
+    assert!(unsafe { read_only.as_mut_slice() }.is_err());
+
+    JavaType::ByteBuffer(buffer)
+        .to_jni_object(&mut env, &java_class)
+        .unwrap()
+        .into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyByteBufferSum<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    buffer: JByteBuffer<'local>,
+) -> jint {
+    let outcome = to_rust(&mut env, &java_class, JValue::from(&buffer)).unwrap();
+    let buffer = DirectByteBuffer::try_from(outcome).unwrap();
+
+    assert!(!buffer.is_rust_owned());
+
+    unsafe { buffer.as_slice() }
+        .iter()
+        .map(|byte| *byte as jint)
+        .sum()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyPoint<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jobject {
+    let point = Class::new("RustyPoint")
+        .argument(JavaType::Int(3))
+        .argument("ORIGIN")
+        .argument_as(
+            JavaTypeSignature::object("java/lang/Integer"),
+            JavaType::Null,
+        );
+
+    assert_eq!(
+        "(ILjava/lang/String;Ljava/lang/Integer;)V",
+        point.constructor().unwrap().descriptor()
+    );
+    assert_eq!(
+        Some(easy_jni::EasyJniError::UntypedNullArgument { index: 1 }),
+        Class::new("RustyPoint")
+            .argument(JavaType::Int(3))
+            .argument(JavaType::Null)
+            .create(&mut env, &java_class)
+            .err()
+    );
+    assert_eq!(
+        Some(easy_jni::EasyJniError::Field {
+            name: "z".to_owned(),
+            error: Box::new(easy_jni::EasyJniError::UntypedNullField),
+        }),
+        point
+            .clone()
+            .field("z", JavaType::Null)
+            .build(&mut env, &java_class)
+            .err()
+    );
+    assert_eq!(
+        Some(easy_jni::EasyJniError::JavaTypeMismatch),
+        Class::new("RustyPoint")
+            .argument(JavaType::Int(3))
+            .argument_as(JavaTypeSignature::String, 3)
+            .argument_as(
+                JavaTypeSignature::object("java/lang/Integer"),
+                JavaType::Null
+            )
+            .create(&mut env, &java_class)
+            .err()
+    );
+    assert_eq!(
+        Some(easy_jni::EasyJniError::Field {
+            name: "z".to_owned(),
+            error: Box::new(easy_jni::EasyJniError::JavaTypeMismatch),
+        }),
+        point
+            .clone()
+            .field_as("z", JavaTypeSignature::object("java/lang/Long"), 1)
+            .build(&mut env, &java_class)
+            .err()
+    );
+
+    point.build(&mut env, &java_class).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyProfileSummary<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    profile: JObject<'local>,
+) -> jstring {
+    let profile = JavaObject::new(&mut env, &java_class, &profile).unwrap();
+    let mut field = |name| profile.field(&mut env, &java_class, name).unwrap();
+
+    let name = String::try_from(field("name")).unwrap();
+    let age = i32::try_from(field("age")).unwrap();
+    let scores = JavaArray::<i32>::try_from(field("scores")).unwrap();
+    let nickname = Option::<String>::try_from(field("nickname")).unwrap();
+    // Declared by the superclass
+    let id = i64::try_from(field("id")).unwrap();
+    let point = JavaObject::try_from(field("point")).unwrap();
+
+    let label = String::try_from(point.field(&mut env, &java_class, "label").unwrap()).unwrap();
+    let score_sum = scores.iter().sum::<i32>();
+
+    assert_eq!(
+        Err(easy_jni::EasyJniError::Field {
+            name: "missing".to_owned(),
+            error: Box::new(easy_jni::EasyJniError::FieldNotFound),
+        }),
+        profile.field(&mut env, &java_class, "missing")
+    );
+    assert!(profile
+        .field_as(&mut env, &java_class, "age", JavaTypeSignature::String)
+        .is_err());
+    env.exception_clear().unwrap();
+
+    let summary = format!("{name}:{age}:{score_sum}:{nickname:?}:{id}:{label}");
+
+    env.new_string(summary).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyConfig<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jint {
+    let config = Class::new("RustyConfig");
+
+    let limit = config.static_field(&mut env, &java_class, "LIMIT").unwrap();
+    let version = Class::new("RustLibrary")
+        .static_field(&mut env, &java_class, "RUSTY_VERSION")
         .unwrap();
-    let field_signature = JavaTypeSignature::String.java_class_name_array().unwrap();
 
-    env.set_field(
-        &object,
-        success_field_name,
-        &field_signature,
-        JValueOwned::Object(JObject::from(success_array)).borrow(),
-    )
-    .unwrap();
+    assert_eq!(
+        Ok(JavaType::from("HELLO")),
+        config.static_field(&mut env, &java_class, "greeting")
+    );
 
-    env.set_field(
-        &object,
-        failure_field_name,
-        &field_signature,
-        JValueOwned::Object(JObject::from(failure_array)).borrow(),
-    )
-    .unwrap();
+    assert!(matches!(
+        config.set_static_field(&mut env, &java_class, "greeting", JavaType::Null),
+        Err(easy_jni::EasyJniError::Field { ref name, ref error })
+            if name == "greeting" && **error == easy_jni::EasyJniError::UntypedNullField
+    ));
+    config
+        .set_static_field(&mut env, &java_class, "greeting", "HELLO FROM RUST")
+        .unwrap();
 
-    object.into_raw()
+    i32::try_from(limit).unwrap() + i32::try_from(version).unwrap()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyCalls<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    point: JObject<'local>,
+) -> jstring {
+    let point = JavaObject::new(&mut env, &java_class, &point).unwrap();
+
+    let description = point
+        .call_method(
+            &mut env,
+            &java_class,
+            "describe",
+            &[JavaType::from(">"), JavaType::Int(2)],
+            JavaTypeSignature::String,
+        )
+        .unwrap();
+    let touched = point
+        .call_method(&mut env, &java_class, "touch", &[], JavaTypeSignature::Void)
+        .unwrap();
+    let sum = Class::new("RustLibrary")
+        .call_static_method(
+            &mut env,
+            &java_class,
+            "rustyAdd",
+            &[JavaType::Int(2), JavaType::Long(40)],
+            JavaTypeSignature::Long,
+        )
+        .unwrap();
+
+    assert_eq!(JavaType::Void, touched);
+    assert!(matches!(
+        point.call_method(
+            &mut env,
+            &java_class,
+            "describe",
+            &[JavaType::Null, JavaType::Int(2)],
+            JavaTypeSignature::String
+        ),
+        Err(easy_jni::EasyJniError::Method { ref error, .. })
+            if **error == easy_jni::EasyJniError::UntypedNullArgument { index: 0 }
+    ));
+    assert!(matches!(
+        point.call_method(&mut env, &java_class, "describe", &[], JavaTypeSignature::String),
+        Err(easy_jni::EasyJniError::Method { ref name, .. }) if name == "describe"
+    ));
+    env.exception_clear().unwrap();
+
+    let summary = format!(
+        "{}:{}",
+        String::try_from(description).unwrap(),
+        i64::try_from(sum).unwrap()
+    );
+
+    env.new_string(summary).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustyOverloads<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jstring {
+    let shape = Class::new("RustyShape")
+        .argument(3)
+        .argument("TRIANGLE")
+        .argument(2)
+        .create_overloaded(&mut env, &java_class)
+        .unwrap();
+    let shape = JavaObject::new(&mut env, &java_class, &shape).unwrap();
+    let default_shape = Class::new("RustyShape")
+        .argument(4)
+        .create_overloaded(&mut env, &java_class)
+        .unwrap();
+    let default_shape = JavaObject::new(&mut env, &java_class, &default_shape).unwrap();
+
+    // Only the synthetic constructor behind the default parameters takes these
+    assert!(matches!(
+        Class::new("RustyShape")
+            .argument(4)
+            .argument("SQUARE")
+            .argument(1.0)
+            .argument(0)
+            .argument_as(
+                JavaTypeSignature::object("kotlin/jvm/internal/DefaultConstructorMarker"),
+                JavaType::Null
+            )
+            .create_overloaded(&mut env, &java_class),
+        Err(easy_jni::EasyJniError::NoApplicableMethod { ref name }) if name == "<init>"
+    ));
+
+    let picks = [
+        JavaType::Int(1),
+        JavaType::Float(1.5),
+        JavaType::Null,
+        JavaType::Boolean(true),
+        JavaType::Object(default_shape.clone()),
+    ]
+    .into_iter()
+    .map(|argument| {
+        let pick = shape
+            .call_overloaded_method(&mut env, &java_class, "pick", &[argument])
+            .unwrap();
+
+        String::try_from(pick).unwrap()
+    })
+    .collect::<Vec<_>>();
+
+    // Overloads follow the class an object is held as, not its runtime class
+    let text = env.new_string("TEXT").unwrap();
+    let text = JavaObject::new(&mut env, &java_class, &text).unwrap();
+    let any = text
+        .cast(&mut env, &java_class, "java.lang.Object")
+        .unwrap();
+
+    for (argument, expected) in [(any, "ANY"), (text, "STRING")] {
+        let pick = shape
+            .call_overloaded_method(&mut env, &java_class, "pick", &[JavaType::Object(argument)])
+            .unwrap();
+        assert_eq!(JavaType::from(expected), pick);
+    }
+
+    let mix = shape
+        .call_overloaded_method(
+            &mut env,
+            &java_class,
+            "mix",
+            &[JavaType::Int(1), JavaType::Long(2)],
+        )
+        .unwrap();
+    assert!(matches!(
+        shape.call_overloaded_method(&mut env, &java_class, "mix", &[JavaType::Int(1), JavaType::Int(2)]),
+        Err(easy_jni::EasyJniError::AmbiguousMethodCall { ref name, ref candidates })
+            if name == "mix" && candidates.len() == 2
+    ));
+    assert!(matches!(
+        shape.call_overloaded_method(&mut env, &java_class, "pick", &[]),
+        Err(easy_jni::EasyJniError::NoApplicableMethod { ref name }) if name == "pick"
+    ));
+
+    let class = Class::new("RustyShape");
+    let scaled_int = class
+        .call_overloaded_static_method(&mut env, &java_class, "scale", &[JavaType::Char('A')])
+        .unwrap();
+    let scaled_long = class
+        .call_overloaded_static_method(&mut env, &java_class, "scale", &[JavaType::Long(5)])
+        .unwrap();
+
+    // Boxed objects are unboxed for the primitive parameter resolution picked
+    for (boxed, scaled) in [
+        (JavaType::Int(7), JavaType::Int(14)),
+        (JavaType::Char('A'), JavaType::Int(130)),
+    ] {
+        let boxed = boxed.to_jni_object(&mut env, &java_class).unwrap();
+        let boxed = JavaObject::new(&mut env, &java_class, &boxed).unwrap();
+
+        assert_eq!(
+            scaled,
+            class
+                .call_overloaded_static_method(
+                    &mut env,
+                    &java_class,
+                    "scale",
+                    &[JavaType::Object(boxed)]
+                )
+                .unwrap()
+        );
+    }
+
+    let summary = format!(
+        "{}:{}:{}:{}:{}:{}:{}:{}",
+        picks.join(","),
+        String::try_from(mix).unwrap(),
+        String::try_from(shape.field(&mut env, &java_class, "name").unwrap()).unwrap(),
+        f64::try_from(shape.field(&mut env, &java_class, "size").unwrap()).unwrap(),
+        String::try_from(default_shape.field(&mut env, &java_class, "name").unwrap()).unwrap(),
+        f64::try_from(default_shape.field(&mut env, &java_class, "size").unwrap()).unwrap(),
+        i32::try_from(scaled_int).unwrap(),
+        i64::try_from(scaled_long).unwrap()
+    );
+
+    env.new_string(summary).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustySuperCalls<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+    animal: JObject<'local>,
+) -> jstring {
+    let dog = JavaObject::new(&mut env, &java_class, &animal).unwrap();
+
+    assert!(dog
+        .is_instance_of(&mut env, &java_class, "RustyAnimal")
+        .unwrap());
+    assert!(!dog
+        .is_instance_of(&mut env, &java_class, "RustyPoint")
+        .unwrap());
+    assert!(Class::new("RustyAnimal")
+        .is_assignable_from(&mut env, &java_class, "RustyDog")
+        .unwrap());
+    assert!(!Class::new("RustyDog")
+        .is_assignable_from(&mut env, &java_class, "RustyAnimal")
+        .unwrap());
+    assert!(dog
+        .is_instance_of(&mut env, &java_class, "java.lang.Object")
+        .unwrap());
+    assert!(Class::new("java/lang/Object")
+        .is_assignable_from(&mut env, &java_class, "java.lang.String")
+        .unwrap());
+    assert!(matches!(
+        dog.cast(&mut env, &java_class, "RustyPoint"),
+        Err(easy_jni::EasyJniError::InvalidCast { ref class_name, ref target })
+            if class_name == "RustyDog" && target == "RustyPoint"
+    ));
+    assert!(matches!(
+        dog.call_nonvirtual_method(&mut env, &java_class, "RustyPoint", "touch", &[], JavaTypeSignature::Void),
+        Err(easy_jni::EasyJniError::Method { ref error, .. })
+            if matches!(**error, easy_jni::EasyJniError::InvalidCast { .. })
+    ));
+
+    let animal = dog.cast(&mut env, &java_class, "RustyAnimal").unwrap();
+    let speak = |env: &mut JNIEnv<'local>, virtual_call: bool| {
+        let arguments = [JavaType::Int(2)];
+        let spoken = if virtual_call {
+            animal.call_method(
+                env,
+                &java_class,
+                "speak",
+                &arguments,
+                JavaTypeSignature::String,
+            )
+        } else {
+            animal.call_nonvirtual_method(
+                env,
+                &java_class,
+                "RustyAnimal",
+                "speak",
+                &arguments,
+                JavaTypeSignature::String,
+            )
+        };
+
+        String::try_from(spoken.unwrap()).unwrap()
+    };
+    let spoken = speak(&mut env, true);
+    let spoken_by_super = speak(&mut env, false);
+    let legs = animal
+        .call_method(&mut env, &java_class, "legs", &[], JavaTypeSignature::Int)
+        .unwrap();
+    let legs_of_super = animal
+        .call_nonvirtual_method(
+            &mut env,
+            &java_class,
+            "RustyAnimal",
+            "legs",
+            &[],
+            JavaTypeSignature::Int,
+        )
+        .unwrap();
+
+    let summary = format!(
+        "{}:{}:{}:{}:{}",
+        spoken,
+        spoken_by_super,
+        i32::try_from(legs).unwrap(),
+        i32::try_from(legs_of_super).unwrap(),
+        animal.class_name()
+    );
+
+    env.new_string(summary).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_rustySingletons<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jstring {
+    let registry = Class::new("RustyRegistry");
+    let counter = Class::new("RustyCounter");
+    let factory = Class::new("RustyFactory");
+
+    let instance = registry.object_instance(&mut env, &java_class).unwrap();
+    assert_eq!("RustyRegistry", instance.class_name());
+
+    let mut values = Vec::new();
+
+    for name in ["ABC", "FERRIS"] {
+        let count = registry
+            .call_object_method(&mut env, &java_class, "register", &[JavaType::from(name)])
+            .unwrap();
+        values.push(i32::try_from(count).unwrap().to_string());
+    }
+
+    for property in ["count", "isOpen", "CAPACITY"] {
+        let value = registry
+            .object_property(&mut env, &java_class, property)
+            .unwrap();
+
+        values.push(match value {
+            JavaType::Int(value) => value.to_string(),
+            JavaType::Boolean(value) => value.to_string(),
+            other => panic!("unexpected property {property}: {other:?}"),
+        });
+    }
+
+    let reset = registry
+        .call_object_method(&mut env, &java_class, "reset", &[])
+        .unwrap();
+    values.push(i32::try_from(reset).unwrap().to_string());
+    assert!(matches!(
+        registry.object_property(&mut env, &java_class, "missing"),
+        Err(easy_jni::EasyJniError::Field { ref name, .. }) if name == "missing"
+    ));
+
+    let next = counter
+        .call_companion_method(&mut env, &java_class, "next", &[JavaType::Int(3)])
+        .unwrap();
+    let total = counter
+        .companion_property(&mut env, &java_class, "total")
+        .unwrap();
+    let label = counter
+        .call_companion_method(&mut env, &java_class, "label", &[])
+        .unwrap();
+    values.push(i64::try_from(next).unwrap().to_string());
+    values.push(i64::try_from(total).unwrap().to_string());
+    values.push(String::try_from(label).unwrap());
+
+    let made = factory
+        .call_companion_method(&mut env, &java_class, "make", &[JavaType::from("ABC")])
+        .unwrap();
+    values.push(String::try_from(made).unwrap());
+
+    env.new_string(values.join(":")).unwrap().into_raw()
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "system" fn Java_RustLibrary_resultOfArrayString<'local>(
+    mut env: JNIEnv<'local>,
+    java_class: JClass<'local>,
+) -> jobject {
+    let success_data = ["ONE", "TWO", "THREE"].map(String::from).to_vec();
+
+    let builder = Class::new("ResultOfArrayString")
+        .field("successData", JavaArray::from(success_data))
+        .field("failureData", JavaArray::<String>::from(Vec::new()));
+
+    let outcome = builder
+        .clone()
+        .field("missingData", JavaType::Int(4))
+        .build(&mut env, &java_class);
+
+    assert!(matches!(
+        outcome,
+        Err(easy_jni::EasyJniError::Field { ref name, .. }) if name == "missingData"
+    ));
+    env.exception_clear().unwrap();
+
+    builder.build(&mut env, &java_class).unwrap().into_raw()
 }
 
 #[allow(non_snake_case)]